@@ -0,0 +1,68 @@
+use common::frames::Recording;
+use serde_json::json;
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+/// Moves the cursor to the top left and clears the screen, so every frame is drawn over the last.
+const CLEAR: &str = "\x1b[H\x1b[2J";
+
+/// Draws the frames one after the other in the terminal, at the given number of frames per second.
+pub fn play(recording: &Recording, fps: f64, out: &mut impl Write) -> io::Result<()> {
+    let delay = Duration::from_secs_f64(1.0 / fps);
+    for frame in &recording.frames {
+        write!(out, "{}{}", CLEAR, frame)?;
+        out.flush()?;
+        thread::sleep(delay);
+    }
+    Ok(())
+}
+
+/// Writes the frames as an asciicast (version 2), which `asciinema play` plays back at the given
+/// number of frames per second.
+pub fn write_cast(
+    recording: &Recording,
+    fps: f64,
+    title: &str,
+    out: &mut impl Write,
+) -> io::Result<()> {
+    let (width, height) = recording.size();
+    let header = json!({
+        "version": 2,
+        "width": width.max(1),
+        "height": height.max(1),
+        "title": title,
+    });
+    writeln!(out, "{}", header)?;
+
+    for (index, frame) in recording.frames.iter().enumerate() {
+        // The terminal is in raw mode during playback, so every line has to return the cursor too
+        let data = format!("{}{}", CLEAR, frame.replace('\n', "\r\n"));
+        let event = json!([index as f64 / fps, "o", data]);
+        writeln!(out, "{}", event)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_cast_has_a_header_and_an_event_per_frame() {
+    let recording = Recording {
+        frames: vec!["#.\n.#\n".to_string(), "..\n##\n".to_string()],
+        dropped: 0,
+    };
+    let mut cast = Vec::new();
+    write_cast(&recording, 4.0, "Day 0", &mut cast).unwrap();
+
+    let lines = String::from_utf8(cast).unwrap();
+    let lines = lines
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        lines[0],
+        json!({"version": 2, "width": 2, "height": 2, "title": "Day 0"})
+    );
+    assert_eq!(lines[1], json!([0.0, "o", "\x1b[H\x1b[2J#.\r\n.#\r\n"]));
+    assert_eq!(lines[2][0], json!(0.25));
+    assert_eq!(lines.len(), 3);
+}
@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+/// The answers of a day that are known to be correct for an input.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct KnownAnswers {
+    pub day: u8,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part1: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part2: Option<Value>,
+}
+
+impl KnownAnswers {
+    pub fn part(&self, part: u8) -> Option<&Value> {
+        match part {
+            1 => self.part1.as_ref(),
+            _ => self.part2.as_ref(),
+        }
+    }
+
+    pub fn part_mut(&mut self, part: u8) -> &mut Option<Value> {
+        match part {
+            1 => &mut self.part1,
+            _ => &mut self.part2,
+        }
+    }
+}
+
+/// The known answers keyed by the SHA-256 of their input, so they stay with the input they belong
+/// to when an input is replaced. Answers are stored like `--format json` prints them.
+pub type AnswerStore = BTreeMap<String, KnownAnswers>;
+
+/// Finds the answers file, `answers.json` in the current directory or else in the checkout the
+/// runner was built from, like the inputs.
+pub fn default_path() -> PathBuf {
+    let relative = PathBuf::from("answers.json");
+    if relative.exists() {
+        return relative;
+    }
+
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(relative)
+}
+
+/// Reads the answers file. A missing file has no answers yet.
+pub fn load(path: &Path) -> io::Result<AnswerStore> {
+    match fs::read_to_string(path) {
+        Ok(text) => serde_json::from_str(&text).map_err(io::Error::from),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(AnswerStore::new()),
+        Err(err) => Err(err),
+    }
+}
+
+pub fn save(path: &Path, answers: &AnswerStore) -> io::Result<()> {
+    let mut text = serde_json::to_string_pretty(answers).map_err(io::Error::from)?;
+    text.push('\n');
+    fs::write(path, text)
+}
+
+#[test]
+fn test_answers_round_trip() {
+    let mut answers = AnswerStore::new();
+    let known = answers.entry("abc".to_string()).or_default();
+    known.day = 5;
+    *known.part_mut(1) = Some(Value::from("WCZTHTMPS"));
+
+    let text = serde_json::to_string(&answers).unwrap();
+    assert_eq!(text, r#"{"abc":{"day":5,"part1":"WCZTHTMPS"}}"#);
+
+    let read: AnswerStore = serde_json::from_str(&text).unwrap();
+    assert_eq!(read["abc"].part(1), Some(&Value::from("WCZTHTMPS")));
+    assert_eq!(read["abc"].part(2), None);
+}
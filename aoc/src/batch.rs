@@ -0,0 +1,147 @@
+use crate::days;
+use common::Answer;
+use rayon::prelude::*;
+use std::fmt::Write as _;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use std::{fs, io};
+
+/// How solving one of the inputs of a batch went.
+#[derive(Debug)]
+pub struct Outcome {
+    pub file: PathBuf,
+    /// The answer to each part with how long it took, or why the input could not be solved.
+    pub result: Result<Vec<(Answer, Duration)>, String>,
+}
+
+/// The files in a directory, sorted by name so a batch always reports them in the same order.
+pub fn files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_file() {
+            files.push(entry.path());
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Solves both parts of a day for every file, spread over all cores. A file that can't be read
+/// or parsed, or that makes the solution panic, only fails that file.
+pub fn solve(day: u8, files: &[PathBuf]) -> Vec<Outcome> {
+    files
+        .par_iter()
+        .map(|file| Outcome {
+            file: file.clone(),
+            result: solve_file(day, file),
+        })
+        .collect()
+}
+
+fn solve_file(day: u8, file: &Path) -> Result<Vec<(Answer, Duration)>, String> {
+    let text = fs::read_to_string(file).map_err(|err| format!("could not read: {}", err))?;
+    let solved = panic::catch_unwind(AssertUnwindSafe(|| days::solve(day, &[1, 2], &text)));
+    match solved {
+        Ok(Some(Ok(answers))) => Ok(answers),
+        Ok(Some(Err(err))) => Err(format!("invalid input: {}", err)),
+        Ok(None) => Err(format!("day {} is not solved", day)),
+        Err(_) => Err("the solution panicked".to_string()),
+    }
+}
+
+/// Renders the outcomes as a table with a row per file. Failed files get their error instead of
+/// answers, and screens, which don't fit in a cell, follow the table.
+pub fn table(outcomes: &[Outcome]) -> String {
+    let name = |outcome: &Outcome| {
+        outcome.file.file_name().map_or_else(
+            || outcome.file.display().to_string(),
+            |name| name.to_string_lossy().into_owned(),
+        )
+    };
+    let cell = |answer: &Answer| {
+        if answer.is_multiline() {
+            "(screen)".to_string()
+        } else {
+            answer.to_string()
+        }
+    };
+
+    let mut rows = vec![[
+        "File".to_string(),
+        "Part 1".to_string(),
+        "Part 2".to_string(),
+        "Time".to_string(),
+    ]];
+    let mut failures = Vec::new();
+    let mut screens = String::new();
+    for outcome in outcomes {
+        match &outcome.result {
+            Ok(answers) => {
+                let (part1, part2) = (&answers[0], &answers[1]);
+                rows.push([
+                    name(outcome),
+                    cell(&part1.0),
+                    cell(&part2.0),
+                    format!("{:.1?}", part1.1 + part2.1),
+                ]);
+                for (part, (answer, _)) in (1..).zip(answers) {
+                    if answer.is_multiline() {
+                        writeln!(screens, "\n{} part {}:\n{}", name(outcome), part, answer)
+                            .unwrap();
+                    }
+                }
+            }
+            Err(err) => failures.push((name(outcome), err)),
+        }
+    }
+
+    let mut widths = [0; 4];
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut table = String::new();
+    for row in &rows {
+        writeln!(
+            table,
+            "{:<w0$}  {:>w1$}  {:>w2$}  {:>w3$}",
+            row[0],
+            row[1],
+            row[2],
+            row[3],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3]
+        )
+        .unwrap();
+    }
+    for (file, err) in failures {
+        writeln!(table, "{:<w$}  FAILED: {}", file, err, w = widths[0]).unwrap();
+    }
+    table + &screens
+}
+
+#[test]
+fn test_failures_do_not_stop_the_batch() {
+    let dir = std::env::temp_dir().join(format!("aoc-batch-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("a.txt"), include_str!("../../day1/example.txt")).unwrap();
+    fs::write(dir.join("b.txt"), "1000\nsnacks\n").unwrap();
+
+    let outcomes = solve(1, &files(&dir).unwrap());
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(outcomes.len(), 2);
+    let answers = outcomes[0].result.as_ref().unwrap();
+    assert_eq!(answers[0].0, Answer::from(24000));
+    assert!(outcomes[1].result.as_ref().unwrap_err().contains("line 2"));
+
+    let table = table(&outcomes);
+    assert!(table.contains("a.txt   24000   45000"), "{}", table);
+    assert!(table.contains("b.txt  FAILED: invalid input"), "{}", table);
+}
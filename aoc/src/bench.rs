@@ -0,0 +1,215 @@
+use common::{parse_input, ParseError, Solution};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+use std::{fs, io};
+
+/// Differences below this are noise from the machine rather than from the solution, so they are
+/// never reported as a regression.
+const NOISE_FLOOR: Duration = Duration::from_micros(100);
+
+/// How long a day took to parse its input and to solve each part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+
+    fn steps(&self) -> [(&'static str, Duration); 3] {
+        [
+            ("parse", self.parse),
+            ("part 1", self.part1),
+            ("part 2", self.part2),
+        ]
+    }
+}
+
+/// Times parsing and both parts of a day, keeping the fastest of the runs as it is the least
+/// disturbed by whatever else the machine is doing.
+pub fn measure<S: Solution>(text: &str, runs: usize) -> Result<Timings, ParseError> {
+    let mut fastest: Option<Timings> = None;
+    for _ in 0..runs.max(1) {
+        let start = Instant::now();
+        let input = parse_input::<S>(black_box(text))?;
+        let parse = start.elapsed();
+
+        let start = Instant::now();
+        black_box(S::part1(&input));
+        let part1 = start.elapsed();
+
+        let start = Instant::now();
+        black_box(S::part2(&input));
+        let part2 = start.elapsed();
+
+        let run = Timings {
+            parse,
+            part1,
+            part2,
+        };
+        fastest = Some(match fastest {
+            Some(fastest) => Timings {
+                parse: fastest.parse.min(run.parse),
+                part1: fastest.part1.min(run.part1),
+                part2: fastest.part2.min(run.part2),
+            },
+            None => run,
+        });
+    }
+    Ok(fastest.unwrap())
+}
+
+/// The timings of a run per day, as saved to compare later runs against.
+pub type Baseline = BTreeMap<u8, Timings>;
+
+/// Reads a baseline saved by [`save_baseline`]. Lines that can't be read are skipped, so a
+/// damaged baseline only loses the days on those lines.
+pub fn load_baseline(path: &Path) -> io::Result<Baseline> {
+    let text = fs::read_to_string(path)?;
+    Ok(text.lines().filter_map(parse_baseline_line).collect())
+}
+
+fn parse_baseline_line(line: &str) -> Option<(u8, Timings)> {
+    if line.starts_with('#') {
+        return None;
+    }
+    let mut fields = line.split('\t');
+    let day = fields.next()?.parse().ok()?;
+    let mut next = || fields.next()?.parse().ok().map(Duration::from_nanos);
+    let timings = Timings {
+        parse: next()?,
+        part1: next()?,
+        part2: next()?,
+    };
+    Some((day, timings))
+}
+
+/// Saves the timings as a baseline, with one tab separated line of nanoseconds per day.
+pub fn save_baseline(path: &Path, baseline: &Baseline) -> io::Result<()> {
+    let mut text = String::from("# day\tparse\tpart 1\tpart 2 (ns)\n");
+    for (day, timings) in baseline {
+        writeln!(
+            text,
+            "{}\t{}\t{}\t{}",
+            day,
+            timings.parse.as_nanos(),
+            timings.part1.as_nanos(),
+            timings.part2.as_nanos()
+        )
+        .unwrap();
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, text)
+}
+
+/// A step of a day that became slower than its baseline by more than the threshold.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Regression {
+    pub day: u8,
+    pub step: &'static str,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+impl Regression {
+    /// How much slower the step became, in percent.
+    pub fn percentage(&self) -> f64 {
+        (self.after.as_secs_f64() / self.before.as_secs_f64() - 1.0) * 100.0
+    }
+}
+
+/// The steps that became slower than the baseline by more than `threshold` percent.
+pub fn regressions(baseline: &Baseline, current: &Baseline, threshold: f64) -> Vec<Regression> {
+    let mut regressions = Vec::new();
+    for (day, timings) in current {
+        let Some(before) = baseline.get(day) else {
+            continue;
+        };
+        for ((step, before), (_, after)) in before.steps().into_iter().zip(timings.steps()) {
+            let limit = before.as_secs_f64() * (1.0 + threshold / 100.0);
+            if after > before + NOISE_FLOOR && after.as_secs_f64() > limit {
+                regressions.push(Regression {
+                    day: *day,
+                    step,
+                    before,
+                    after,
+                });
+            }
+        }
+    }
+    regressions
+}
+
+/// Renders the timings as a table, with a row per day and a row with the totals.
+pub fn table(timings: &Baseline) -> String {
+    let mut table = format!(
+        "{:>4}  {:>12}  {:>12}  {:>12}  {:>12}\n",
+        "Day", "Parse", "Part 1", "Part 2", "Total"
+    );
+    let mut row = |day: &str, t: &Timings| {
+        writeln!(
+            table,
+            "{:>4}  {:>12}  {:>12}  {:>12}  {:>12}",
+            day,
+            format!("{:.1?}", t.parse),
+            format!("{:.1?}", t.part1),
+            format!("{:.1?}", t.part2),
+            format!("{:.1?}", t.total())
+        )
+        .unwrap();
+    };
+
+    let mut total = Timings {
+        parse: Duration::ZERO,
+        part1: Duration::ZERO,
+        part2: Duration::ZERO,
+    };
+    for (day, timings) in timings {
+        row(&day.to_string(), timings);
+        total.parse += timings.parse;
+        total.part1 += timings.part1;
+        total.part2 += timings.part2;
+    }
+    row("All", &total);
+    table
+}
+
+#[test]
+fn test_baseline_lines() {
+    let line = "7\t1000\t2000000\t3";
+    let (day, timings) = parse_baseline_line(line).unwrap();
+    assert_eq!(day, 7);
+    assert_eq!(timings.part1, Duration::from_millis(2));
+    assert_eq!(parse_baseline_line("# day\tparse"), None);
+    assert_eq!(parse_baseline_line("7\t1000"), None);
+}
+
+#[test]
+fn test_regressions_ignore_noise() {
+    let timings = |part1| Timings {
+        parse: Duration::from_micros(10),
+        part1,
+        part2: Duration::from_millis(10),
+    };
+    let baseline = Baseline::from([(1, timings(Duration::from_millis(10)))]);
+
+    // Twice as slow, but only by a few microseconds
+    let mut current = Baseline::from([(1, timings(Duration::from_millis(10)))]);
+    current.get_mut(&1).unwrap().parse = Duration::from_micros(20);
+    assert_eq!(regressions(&baseline, &current, 10.0), vec![]);
+
+    let current = Baseline::from([(1, timings(Duration::from_millis(15)))]);
+    let found = regressions(&baseline, &current, 10.0);
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].step, "part 1");
+    assert_eq!(found[0].percentage().round(), 50.0);
+}
@@ -0,0 +1,271 @@
+use crate::output::answer_to_json;
+use clap::ValueEnum;
+use common::Answer;
+use day1::stats::Report;
+use day1::top::RankedElf;
+use serde_json::{json, Value};
+use std::fmt::Write as _;
+
+/// How `calories stats` prints the report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ReportFormat {
+    /// Aligned sections with a histogram drawn in text, for people
+    Text,
+    /// A `section,key,value` row per number, for spreadsheets
+    Csv,
+    /// A single JSON object, for other programs
+    Json,
+}
+
+/// The widest a bar of the histogram is drawn, for the bucket with the most elves.
+const BAR_WIDTH: usize = 40;
+
+/// Renders the ranking as a table with a row per elf, followed by the total of all of them.
+pub fn top_table(ranking: &[RankedElf]) -> String {
+    let width = ranking
+        .iter()
+        .map(|ranked| ranked.elf.to_string().len())
+        .max()
+        .unwrap_or(0)
+        .max("Total".len());
+    let total = ranking
+        .iter()
+        .map(|ranked| i128::from(ranked.calories))
+        .sum::<i128>();
+    let calories_width = total.to_string().len().max("Calories".len());
+
+    let mut table = format!(
+        "Rank  {:>w$}  {:>cw$}\n",
+        "Elf",
+        "Calories",
+        w = width,
+        cw = calories_width
+    );
+    for (rank, ranked) in (1..).zip(ranking) {
+        writeln!(
+            table,
+            "{:>4}  {:>w$}  {:>cw$}",
+            rank,
+            ranked.elf,
+            ranked.calories,
+            w = width,
+            cw = calories_width
+        )
+        .unwrap();
+    }
+    writeln!(
+        table,
+        "{:<4}  {:>w$}  {:>cw$}",
+        "",
+        "Total",
+        total,
+        w = width,
+        cw = calories_width
+    )
+    .unwrap();
+    table
+}
+
+/// The ranking as a JSON array with an object per elf, from most to least calories.
+pub fn top_to_json(ranking: &[RankedElf]) -> Value {
+    let elves = (1..)
+        .zip(ranking)
+        .map(|(rank, ranked)| json!({"rank": rank, "elf": ranked.elf, "calories": calories_to_json(ranked.calories)}))
+        .collect();
+    Value::Array(elves)
+}
+
+/// Calories as a JSON number, or a string when there are too many of them to read back as a
+/// number without losing digits, like the answers are printed.
+fn calories_to_json(calories: impl Into<Answer>) -> Value {
+    answer_to_json(&calories.into())
+}
+
+/// Renders the report as sections of aligned lines, ending with the histogram.
+pub fn stats_text(report: &Report, z_threshold: f64) -> String {
+    let mut text = String::new();
+    writeln!(text, "Elves:    {}", report.elves).unwrap();
+    writeln!(text, "Items:    {}", report.items).unwrap();
+    writeln!(text, "Total:    {}", report.total).unwrap();
+    writeln!(text, "Min:      {}", report.min).unwrap();
+    writeln!(text, "Max:      {}", report.max).unwrap();
+    writeln!(text, "Mean:     {:.1}", report.mean).unwrap();
+    writeln!(text, "Median:   {:.1}", report.median).unwrap();
+    writeln!(text, "Std dev:  {:.1}", report.std_dev).unwrap();
+
+    text.push_str("\nPercentiles\n");
+    for (percentage, calories) in &report.percentiles {
+        writeln!(text, "  p{:<3} {:.1}", percentage, calories).unwrap();
+    }
+
+    text.push_str("\nItems per elf\n");
+    for (items, elves) in &report.item_counts {
+        writeln!(text, "  {:>3}  {} elves", items, elves).unwrap();
+    }
+
+    writeln!(
+        text,
+        "\nOutliers beyond {} standard deviations",
+        z_threshold
+    )
+    .unwrap();
+    if report.outliers.is_empty() {
+        text.push_str("  none\n");
+    }
+    for outlier in &report.outliers {
+        writeln!(
+            text,
+            "  elf {}  {} calories  z = {:.2}",
+            outlier.elf, outlier.calories, outlier.z_score
+        )
+        .unwrap();
+    }
+
+    text.push_str("\nHistogram\n");
+    let ranges = report
+        .histogram
+        .iter()
+        .map(|bucket| format!("{}..={}", bucket.start, bucket.end))
+        .collect::<Vec<_>>();
+    let width = ranges.iter().map(String::len).max().unwrap_or(0);
+    let most = report
+        .histogram
+        .iter()
+        .map(|bucket| bucket.elves)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    for (range, bucket) in ranges.iter().zip(&report.histogram) {
+        let bar = "#".repeat((bucket.elves * BAR_WIDTH).div_ceil(most));
+        writeln!(
+            text,
+            "  {:>w$}  {:<bw$}  {}",
+            range,
+            bar,
+            bucket.elves,
+            w = width,
+            bw = BAR_WIDTH
+        )
+        .unwrap();
+    }
+    text
+}
+
+/// Renders the report in long form, a `section,key,value` row per number, so every section fits
+/// in the same columns.
+pub fn stats_csv(report: &Report, z_threshold: f64) -> String {
+    let mut csv = String::from("section,key,value\n");
+    let summary = [
+        ("elves", report.elves.to_string()),
+        ("items", report.items.to_string()),
+        ("total", report.total.to_string()),
+        ("min", report.min.to_string()),
+        ("max", report.max.to_string()),
+        ("mean", report.mean.to_string()),
+        ("median", report.median.to_string()),
+        ("std_dev", report.std_dev.to_string()),
+        ("outlier_z_score", z_threshold.to_string()),
+    ];
+    for (key, value) in summary {
+        writeln!(csv, "summary,{},{}", key, value).unwrap();
+    }
+    for (percentage, calories) in &report.percentiles {
+        writeln!(csv, "percentile,p{},{}", percentage, calories).unwrap();
+    }
+    for (items, elves) in &report.item_counts {
+        writeln!(csv, "items,{},{}", items, elves).unwrap();
+    }
+    for bucket in &report.histogram {
+        writeln!(
+            csv,
+            "histogram,{}..={},{}",
+            bucket.start, bucket.end, bucket.elves
+        )
+        .unwrap();
+    }
+    for outlier in &report.outliers {
+        writeln!(csv, "outlier,{},{}", outlier.elf, outlier.calories).unwrap();
+    }
+    csv
+}
+
+/// The report as a JSON object, with the z-score of every outlier.
+pub fn stats_to_json(report: &Report, z_threshold: f64) -> Value {
+    json!({
+        "elves": report.elves,
+        "items": report.items,
+        "total": calories_to_json(report.total),
+        "min": calories_to_json(report.min),
+        "max": calories_to_json(report.max),
+        "mean": report.mean,
+        "median": report.median,
+        "std_dev": report.std_dev,
+        "percentiles": report
+            .percentiles
+            .iter()
+            .map(|(percentage, calories)| json!({"percentile": percentage, "calories": calories}))
+            .collect::<Vec<_>>(),
+        "items_per_elf": report
+            .item_counts
+            .iter()
+            .map(|(items, elves)| json!({"items": items, "elves": elves}))
+            .collect::<Vec<_>>(),
+        "outlier_z_score": z_threshold,
+        "outliers": report
+            .outliers
+            .iter()
+            .map(|outlier| json!({"elf": outlier.elf, "calories": calories_to_json(outlier.calories), "z_score": outlier.z_score}))
+            .collect::<Vec<_>>(),
+        "histogram": report
+            .histogram
+            .iter()
+            .map(|bucket| json!({"start": calories_to_json(bucket.start), "end": calories_to_json(bucket.end), "elves": bucket.elves}))
+            .collect::<Vec<_>>(),
+    })
+}
+
+#[test]
+fn test_top_table() {
+    let ranking = [
+        RankedElf {
+            elf: 4,
+            calories: 24000,
+        },
+        RankedElf {
+            elf: 12,
+            calories: 11000,
+        },
+    ];
+    assert_eq!(
+        top_table(&ranking),
+        "Rank    Elf  Calories\n   1      4     24000\n   2     12     11000\n      Total     35000\n"
+    );
+    assert_eq!(
+        top_to_json(&ranking)[1],
+        json!({"rank": 2, "elf": 12, "calories": 11000})
+    );
+}
+
+#[test]
+fn test_stats() {
+    let elfs = day1::parse(include_str!("../../day1/example.txt")).unwrap();
+    let report = day1::stats::report(&elfs, 1.5, std::num::NonZeroUsize::new(4).unwrap()).unwrap();
+
+    let text = stats_text(&report, 1.5);
+    assert!(text.contains("Std dev:  6985.7\n"));
+    assert!(text.contains("  elf 4  24000 calories  z = 1.86\n"));
+    assert!(text.contains(&format!("    4000..=9000  {:<40}  2\n", "#".repeat(40))));
+    assert!(text.contains(&format!("  14002..=19002  {:<40}  0\n", "")));
+
+    let csv = stats_csv(&report, 1.5);
+    assert!(csv.starts_with("section,key,value\nsummary,elves,5\n"));
+    assert!(csv.contains("\npercentile,p25,6000\n"));
+    assert!(csv.contains("\nitems,3,2\n"));
+    assert!(csv.contains("\nhistogram,19003..=24000,1\n"));
+    assert!(csv.ends_with("\noutlier,4,24000\n"));
+
+    let json = stats_to_json(&report, 1.5);
+    assert_eq!(json["median"], json!(10000.0));
+    assert_eq!(json["outliers"][0]["elf"], json!(4));
+    assert_eq!(json["histogram"].as_array().unwrap().len(), 4);
+}
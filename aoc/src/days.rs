@@ -0,0 +1,146 @@
+use crate::bench::{self, Timings};
+use common::stream::{self, StreamError};
+use common::{parse_input, Answer, ParseError, Solution};
+use std::io::BufRead;
+use std::time::{Duration, Instant};
+
+/// Parses the input once and solves the given parts of a day, timing how long each part took.
+fn solve_with<S: Solution>(
+    text: &str,
+    parts: &[u8],
+) -> Result<Vec<(Answer, Duration)>, ParseError> {
+    let _day = tracing::info_span!("day", day = S::DAY).entered();
+    let input = tracing::info_span!("parse").in_scope(|| parse_input::<S>(text))?;
+    Ok(parts
+        .iter()
+        .map(|part| {
+            let _part = tracing::info_span!("part", part).entered();
+            let start = Instant::now();
+            let answer = match part {
+                1 => S::part1(&input),
+                _ => S::part2(&input),
+            };
+            (answer, start.elapsed())
+        })
+        .collect())
+}
+
+/// Generates the dispatch table from a day number to that day's [`Solution`].
+macro_rules! days {
+    ($($day:literal => $krate:ident::$solution:ident),* $(,)?) => {
+        /// Runs the given parts of the given day on the input and returns their answers in order,
+        /// with how long they took. Returns `None` when the day does not exist, and an error when
+        /// its input is malformed.
+        pub fn solve(
+            day: u8,
+            parts: &[u8],
+            text: &str,
+        ) -> Option<Result<Vec<(Answer, Duration)>, ParseError>> {
+            match day {
+                $($day => Some(solve_with::<$krate::$solution>(text, parts)),)*
+                _ => None,
+            }
+        }
+
+        /// Times parsing and both parts of the given day on the input, over the given number of
+        /// runs. Returns `None` when the day does not exist.
+        pub fn measure(day: u8, text: &str, runs: usize) -> Option<Result<Timings, ParseError>> {
+            match day {
+                $($day => Some(bench::measure::<$krate::$solution>(text, runs)),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+days! {
+    1 => day1::Day1,
+    2 => day2::Day2,
+    3 => day3::Day3,
+    4 => day4::Day4,
+    5 => day5::Day5,
+    6 => day6::Day6,
+    7 => day7::Day7,
+    8 => day8::Day8,
+    9 => day9::Day9,
+    10 => day10::Day10,
+    11 => day11::Day11,
+    12 => day12::Day12,
+    13 => day13::Day13,
+    14 => day14::Day14,
+    15 => day15::Day15,
+    16 => day16::Day16,
+    17 => day17::Day17,
+    18 => day18::Day18,
+    19 => day19::Day19,
+    20 => day20::Day20,
+    21 => day21::Day21,
+    22 => day22::Day22,
+    23 => day23::Day23,
+    24 => day24::Day24,
+    25 => day25::Day25,
+}
+
+/// Solves both parts of a day while reading its input a line at a time, see
+/// [`common::stream`]. Returns `None` for the days that need their whole input at once.
+pub fn stream(day: u8, reader: impl BufRead) -> Option<Result<[Answer; 2], StreamError>> {
+    let _day = tracing::info_span!("day", day).entered();
+    match day {
+        1 => Some(stream::solve::<day1::Day1>(reader)),
+        2 => Some(stream::solve::<day2::Day2>(reader)),
+        3 => Some(stream::solve::<day3::Day3>(reader)),
+        4 => Some(stream::solve::<day4::Day4>(reader)),
+        10 => Some(stream::solve::<day10::Day10>(reader)),
+        18 => Some(stream::solve::<day18::Day18>(reader)),
+        20 => Some(stream::solve::<day20::Day20>(reader)),
+        25 => Some(stream::solve::<day25::Day25>(reader)),
+        _ => None,
+    }
+}
+
+/// Generates a random puzzle input for a day, the same one for the same seed. Returns `None` for
+/// the days without a generator.
+pub fn generate(day: u8, seed: u64) -> Option<String> {
+    match day {
+        12 => Some(day12::generate::input(seed)),
+        15 => Some(day15::generate::input(seed)),
+        16 => Some(day16::generate::input(seed)),
+        23 => Some(day23::generate::input(seed)),
+        24 => Some(day24::generate::input(seed)),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_answers_do_not_depend_on_the_threads() {
+    let examples = [
+        (15, include_str!("../../day15/example.txt")),
+        (19, include_str!("../../day19/example.txt")),
+        (23, include_str!("../../day23/example.txt")),
+    ];
+    let answers_on = |threads: usize, day: u8, text: &str| {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap();
+        let solved = pool.install(|| solve(day, &[1, 2], text));
+        let answers = solved.unwrap().unwrap();
+        answers
+            .into_iter()
+            .map(|(answer, _)| answer)
+            .collect::<Vec<_>>()
+    };
+
+    for (day, text) in examples {
+        let sequential = answers_on(1, day, text);
+        for threads in [2, 8] {
+            assert_eq!(
+                answers_on(threads, day, text),
+                sequential,
+                "day {} on {} threads",
+                day,
+                threads
+            );
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use day2::analysis::Analysis;
+use day2::game::Game;
+use serde_json::{json, Value};
+use std::fmt::Write as _;
+
+/// Renders every reading of the guide as a row of a table, from the highest score to the lowest,
+/// followed by the reading most plausibly meant.
+pub fn analysis_table(game: &Game, analysis: &Analysis) -> String {
+    let descriptions = analysis
+        .readings
+        .iter()
+        .map(|reading| analysis.describe(game, reading))
+        .collect::<Vec<_>>();
+    let width = descriptions
+        .iter()
+        .map(String::len)
+        .max()
+        .unwrap_or(0)
+        .max("Reading".len());
+
+    let mut table = format!(
+        "Rank  {:<w$}  {:>8}  {:>6}  {:>6}  {:>6}  Information\n",
+        "Reading",
+        "Score",
+        "Won",
+        "Drawn",
+        "Lost",
+        w = width
+    );
+    for (rank, (reading, description)) in (1..).zip(analysis.readings.iter().zip(&descriptions)) {
+        writeln!(
+            table,
+            "{:>4}  {:<w$}  {:>8}  {:>6}  {:>6}  {:>6}  {:>11.3}",
+            rank,
+            description,
+            reading.score,
+            reading.wins,
+            reading.draws,
+            reading.losses,
+            reading.information,
+            w = width
+        )
+        .unwrap();
+    }
+
+    match analysis.plausible {
+        Some(index) => writeln!(table, "\nMost plausible: {}", descriptions[index]).unwrap(),
+        None => table.push_str("\nThe second column can't be read as shapes or outcomes\n"),
+    }
+    table
+}
+
+/// The analysis as a JSON object with every reading, from the highest score to the lowest, and
+/// the rank of the one most plausibly meant.
+pub fn analysis_to_json(game: &Game, analysis: &Analysis) -> Value {
+    let readings = analysis
+        .readings
+        .iter()
+        .map(|reading| {
+            json!({
+                "reading": analysis.describe(game, reading),
+                "score": reading.score,
+                "wins": reading.wins,
+                "draws": reading.draws,
+                "losses": reading.losses,
+                "information": reading.information,
+                "in_order": reading.in_order,
+            })
+        })
+        .collect::<Vec<_>>();
+    json!({
+        "readings": readings,
+        "most_plausible_rank": analysis.plausible.map(|index| index + 1),
+    })
+}
+
+#[test]
+fn test_analysis_table() {
+    let game = Game::rock_paper_scissors();
+    let analysis = day2::analysis::analyse(game, include_str!("../../day2/example.txt")).unwrap();
+
+    let table = analysis_table(game, &analysis);
+    let lines = table.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 1 + 12 + 2);
+    assert_eq!(
+        lines[0],
+        "Rank  Reading                               Score     Won   Drawn    Lost  Information"
+    );
+    assert!(table.ends_with("\nMost plausible: X = Rock, Y = Paper, Z = Scissors\n"));
+
+    let json = analysis_to_json(game, &analysis);
+    let rank = json["most_plausible_rank"].as_u64().unwrap() as usize;
+    assert_eq!(
+        json["readings"][rank - 1]["reading"],
+        json!("X = Rock, Y = Paper, Z = Scissors")
+    );
+    assert_eq!(json["readings"][rank - 1]["in_order"], json!(true));
+}
@@ -0,0 +1,38 @@
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+/// Finds the default input file for a day.
+/// Prefers `day<N>/input.txt` relative to the current directory, so the runner works from the
+/// repository root, and falls back to the checkout the runner was built from.
+pub fn default_path(day: u8) -> PathBuf {
+    let relative = PathBuf::from(format!("day{}/input.txt", day));
+    if relative.exists() {
+        return relative;
+    }
+
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(relative)
+}
+
+/// Opens the puzzle input at the given path, or stdin when the path is `-`, to be read bit by bit
+/// instead of all at once.
+pub fn open(path: &Path) -> io::Result<Box<dyn Read + Send>> {
+    if path == Path::new("-") {
+        Ok(Box::new(io::stdin()))
+    } else {
+        Ok(Box::new(fs::File::open(path)?))
+    }
+}
+
+/// Reads the puzzle input from the given path, or from stdin when the path is `-`.
+pub fn read(path: &Path) -> io::Result<String> {
+    if path == Path::new("-") {
+        let mut text = String::new();
+        io::stdin().read_to_string(&mut text)?;
+        Ok(text)
+    } else {
+        fs::read_to_string(path)
+    }
+}
@@ -0,0 +1,930 @@
+use calories::ReportFormat;
+use clap::{Parser, Subcommand};
+use common::budget::{self, Budget, CountingAllocator, Exceeded};
+use common::stream::StreamError;
+use common::{frames, Answer};
+use day2::game::Game;
+use output::{Format, Report};
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, ErrorKind, Write};
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+mod animate;
+mod answers;
+mod batch;
+mod bench;
+mod calories;
+mod days;
+mod guide;
+mod input;
+mod output;
+mod serve;
+mod trace;
+mod watch;
+
+// Counts the memory in use, so the memory limit of `run` can be enforced
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[derive(Debug, Parser)]
+#[command(about = "Runs the Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Log what the solutions are doing to stderr: -v for how long every day, part and phase
+    /// took, -vv for their progress as well, -vvv for everything
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    /// Write how long every span took to this file as folded stacks, for a flame graph
+    #[arg(long, global = true, value_name = "PATH")]
+    flame: Option<PathBuf>,
+
+    /// How many threads the days that work in parallel may use, 1 to run them sequentially
+    /// [default: one per core]
+    #[arg(
+        long,
+        global = true,
+        value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..)
+    )]
+    threads: Option<usize>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Run a day against its input file, a given file or stdin
+    Run {
+        /// The day to run
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Only run a single part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// The puzzle input, or `-` to read it from stdin [default: day<N>/input.txt]
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// How to print the answers
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+
+        /// Stop a part that takes longer than this many seconds
+        #[arg(long, value_name = "SECONDS", value_parser = parse_seconds)]
+        time_limit: Option<Duration>,
+
+        /// Stop a part that uses more than this many MiB of memory
+        #[arg(long, value_name = "MIB", value_parser = clap::value_parser!(u64).range(1..))]
+        memory_limit: Option<u64>,
+
+        /// Read the input a line at a time instead of all at once, solving both parts together.
+        /// Works for days 1, 2, 3, 4, 10, 18, 20 and 25
+        #[arg(long)]
+        stream: bool,
+    },
+    /// Time parsing and both parts of every day on its input file, and compare with a baseline
+    Bench {
+        /// The days to time [default: every day]
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        days: Vec<u8>,
+
+        /// How often to run each day, keeping the fastest run
+        #[arg(long, default_value_t = 1)]
+        runs: usize,
+
+        /// The baseline to compare with
+        #[arg(long, default_value = "target/aoc-bench-baseline.tsv")]
+        baseline: PathBuf,
+
+        /// Save this run as the new baseline
+        #[arg(long)]
+        save: bool,
+
+        /// Report steps that became slower than the baseline by more than this percentage
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Run both parts of a day on every file in a directory, in parallel
+    Batch {
+        /// The day to run
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// The directory with the puzzle inputs
+        dir: PathBuf,
+    },
+    /// Run every day on its input file and check the answers against the known ones
+    Verify {
+        /// The days to verify [default: every day]
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        days: Vec<u8>,
+
+        /// The known answers [default: answers.json]
+        #[arg(long)]
+        answers: Option<PathBuf>,
+
+        /// Add the answers that are not known yet to the known answers
+        #[arg(long)]
+        record: bool,
+    },
+    /// Watch the simulation of a day in the terminal, or save it as an asciinema recording
+    Animate {
+        /// The day to animate
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// The part to animate
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// The puzzle input, or `-` to read it from stdin [default: day<N>/input.txt]
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// How many frames to show per second
+        #[arg(long, default_value_t = 10.0)]
+        fps: f64,
+
+        /// Write the frames to this asciicast file instead of playing them
+        #[arg(long)]
+        cast: Option<PathBuf>,
+
+        /// Keep at most this many frames, as some simulations draw a lot of them
+        #[arg(long, default_value_t = 10_000)]
+        limit: usize,
+    },
+    /// Serve the solutions over HTTP: POST the puzzle input to /day/<N>/part/<P>
+    Serve {
+        /// The address to listen on
+        #[arg(long, default_value = "127.0.0.1:8080")]
+        address: String,
+    },
+    /// Run a day again every time its input or its sources change
+    Watch {
+        /// The day to watch
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// The puzzle input to watch [default: day<N>/input.txt]
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// Poll for changes, for file systems that don't send notifications of them
+        #[arg(long)]
+        poll: bool,
+    },
+    /// Look closer at the calories the elves of day 1 carry
+    Calories {
+        #[command(subcommand)]
+        command: CaloriesCommand,
+    },
+    /// Look closer at the strategy guide of day 2
+    Guide {
+        #[command(subcommand)]
+        command: GuideCommand,
+    },
+    /// Write a random puzzle input for a day to stdout
+    Generate {
+        /// The day to generate an input for
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// The seed of the input, the same seed gives the same input [default: random]
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum CaloriesCommand {
+    /// Rank the elves carrying the most calories, reading the input a line at a time
+    Top {
+        /// How many elves to rank
+        #[arg(short, long, default_value_t = 3, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+        k: usize,
+
+        /// The puzzle input, or `-` to read it from stdin [default: day1/input.txt]
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// How to print the ranking
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Summarize the calories of all elves, with percentiles, outliers and a histogram
+    Stats {
+        /// The puzzle input, or `-` to read it from stdin [default: day1/input.txt]
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// How to print the report
+        #[arg(long, value_enum, default_value_t = ReportFormat::Text)]
+        format: ReportFormat,
+
+        /// How many standard deviations from the mean an elf has to be to count as an outlier
+        #[arg(long, default_value_t = 3.0)]
+        z_score: f64,
+
+        /// How many buckets the histogram has at most
+        #[arg(long, default_value = "10")]
+        bins: NonZeroUsize,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum GuideCommand {
+    /// Score the strategy guide in a game from a rules file, like rock paper scissors lizard Spock
+    Play {
+        /// The rules of the game, see day2/rules for the format
+        #[arg(long)]
+        rules: PathBuf,
+
+        /// The puzzle input, or `-` to read it from stdin [default: day2/input.txt]
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Score every way of reading the second column, and tell which one was most likely meant
+    Analyse {
+        /// The rules of the game, see day2/rules for the format [default: rock paper scissors]
+        #[arg(long)]
+        rules: Option<PathBuf>,
+
+        /// The puzzle input, or `-` to read it from stdin [default: day2/input.txt]
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// How to print the readings
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    // Held until the end, as the flame graph is only written out once it is dropped
+    let _flame = match trace::init(cli.verbose, cli.flame.as_deref()) {
+        Ok(flame) => flame,
+        Err(err) => {
+            eprintln!("Could not start tracing: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Some(threads) = cli.threads {
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(threads);
+        if let Err(err) = pool.build_global() {
+            eprintln!("Could not start {} threads: {}", threads, err);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+            time_limit,
+            memory_limit,
+            stream,
+        } => {
+            let budget = Budget {
+                time: time_limit,
+                memory: memory_limit.map(|mib| (mib as usize) << 20),
+            };
+            let path = input.unwrap_or_else(|| input::default_path(day));
+            let parts = match part {
+                Some(part) => vec![part],
+                None => vec![1, 2],
+            };
+            if stream {
+                run_streaming(day, &parts, &path, format, budget)
+            } else {
+                run(day, &parts, &path, format, budget)
+            }
+        }
+        Command::Bench {
+            days,
+            runs,
+            baseline,
+            save,
+            threshold,
+        } => bench(days, runs, &baseline, save, threshold),
+        Command::Batch { day, dir } => batch(day, &dir),
+        Command::Verify {
+            days,
+            answers,
+            record,
+        } => verify(days, answers, record),
+        Command::Animate {
+            day,
+            part,
+            input,
+            fps,
+            cast,
+            limit,
+        } => animate(day, part, input, fps, cast, limit),
+        Command::Watch { day, input, poll } => watch(day, input, poll),
+        Command::Calories { command } => match command {
+            CaloriesCommand::Top { k, input, format } => calories_top(k, input, format),
+            CaloriesCommand::Stats {
+                input,
+                format,
+                z_score,
+                bins,
+            } => calories_stats(input, format, z_score, bins),
+        },
+        Command::Guide { command } => match command {
+            GuideCommand::Play { rules, input } => guide_play(&rules, input),
+            GuideCommand::Analyse {
+                rules,
+                input,
+                format,
+            } => guide_analyse(rules, input, format),
+        },
+        Command::Generate { day, seed } => generate(day, seed),
+        Command::Serve { address } => serve(&address),
+    }
+}
+
+fn run(day: u8, parts: &[u8], path: &Path, format: Format, budget: Budget) -> ExitCode {
+    let text = match input::read(path) {
+        Ok(text) => text,
+        Err(err) => {
+            eprintln!("Could not read {}: {}", path.display(), err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // Each part gets a budget of its own, so the answer to the first part is not lost when the
+    // second part runs out of its budget
+    let input_sha256 = output::sha256(&text);
+    let mut exceeded_any = false;
+    for &part in parts {
+        let (answer, elapsed) = match budget::run(budget, || days::solve(day, &[part], &text)) {
+            Ok(Some(Ok(mut answers))) => answers.remove(0),
+            Ok(Some(Err(err))) => {
+                eprintln!("Invalid input in {}: {}", path.display(), err);
+                return ExitCode::FAILURE;
+            }
+            Ok(None) => {
+                eprintln!("Day {} is not solved", day);
+                return ExitCode::FAILURE;
+            }
+            Err(exceeded) => {
+                print_exceeded(day, part, &exceeded, format);
+                exceeded_any = true;
+                continue;
+            }
+        };
+        print_answer(day, part, &answer, elapsed, &input_sha256, format);
+    }
+
+    if exceeded_any {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Like [`run`], but reads the input a line at a time, so it never has to be in memory as a
+/// whole. Both parts are solved in the same pass over the input, so they share their budget and
+/// the time they took.
+fn run_streaming(day: u8, parts: &[u8], path: &Path, format: Format, budget: Budget) -> ExitCode {
+    let reader = match input::open(path) {
+        Ok(reader) => reader,
+        Err(err) => {
+            eprintln!("Could not read {}: {}", path.display(), err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut reader = BufReader::new(output::Sha256Reader::new(reader));
+
+    let start = Instant::now();
+    let answers = match budget::run(budget, || days::stream(day, &mut reader)) {
+        Ok(Some(Ok(answers))) => answers,
+        Ok(Some(Err(StreamError::Io(err)))) => {
+            eprintln!("Could not read {}: {}", path.display(), err);
+            return ExitCode::FAILURE;
+        }
+        Ok(Some(Err(StreamError::Parse(err)))) => {
+            eprintln!("Invalid input in {}: {}", path.display(), err);
+            return ExitCode::FAILURE;
+        }
+        Ok(None) => {
+            eprintln!("Day {} can't be streamed, run it without --stream", day);
+            return ExitCode::FAILURE;
+        }
+        Err(exceeded) => {
+            for &part in parts {
+                print_exceeded(day, part, &exceeded, format);
+            }
+            return ExitCode::FAILURE;
+        }
+    };
+    let elapsed = start.elapsed();
+
+    let input_sha256 = reader.into_inner().sha256();
+    for &part in parts {
+        let answer = &answers[part as usize - 1];
+        print_answer(day, part, answer, elapsed, &input_sha256, format);
+    }
+    ExitCode::SUCCESS
+}
+
+fn print_answer(
+    day: u8,
+    part: u8,
+    answer: &Answer,
+    elapsed: Duration,
+    input_sha256: &str,
+    format: Format,
+) {
+    match format {
+        Format::Text if answer.is_multiline() => println!("Part{}:\n{}", part, answer),
+        Format::Text => println!("Part{}: {}", part, answer),
+        Format::Json => {
+            let report = Report::new(day, part, answer, elapsed, input_sha256);
+            println!("{}", serde_json::to_string(&report).unwrap());
+        }
+    }
+}
+
+fn print_exceeded(day: u8, part: u8, exceeded: &Exceeded, format: Format) {
+    match format {
+        Format::Text => println!("Part{}: {}", part, exceeded),
+        Format::Json => println!("{}", output::exceeded_to_json(day, part, exceeded)),
+    }
+}
+
+fn parse_seconds(text: &str) -> Result<Duration, String> {
+    let seconds = text.parse::<f64>().map_err(|err| err.to_string())?;
+    Duration::try_from_secs_f64(seconds)
+        .ok()
+        .filter(|duration| !duration.is_zero())
+        .ok_or_else(|| "expected a positive number of seconds".to_string())
+}
+
+fn bench(days: Vec<u8>, runs: usize, baseline: &Path, save: bool, threshold: f64) -> ExitCode {
+    let days = if days.is_empty() {
+        (1..=25).collect()
+    } else {
+        days
+    };
+
+    let mut timings = bench::Baseline::new();
+    for day in days {
+        let path = input::default_path(day);
+        let text = match input::read(&path) {
+            Ok(text) => text,
+            Err(err) => {
+                eprintln!(
+                    "Skipping day {}, could not read {}: {}",
+                    day,
+                    path.display(),
+                    err
+                );
+                continue;
+            }
+        };
+
+        match days::measure(day, &text, runs) {
+            Some(Ok(day_timings)) => {
+                timings.insert(day, day_timings);
+            }
+            Some(Err(err)) => {
+                eprintln!("Invalid input in {}: {}", path.display(), err);
+                return ExitCode::FAILURE;
+            }
+            None => eprintln!("Skipping day {}, it is not solved", day),
+        }
+    }
+
+    print!("{}", bench::table(&timings));
+
+    match bench::load_baseline(baseline) {
+        Ok(before) => {
+            let regressions = bench::regressions(&before, &timings, threshold);
+            if regressions.is_empty() {
+                println!("No regressions compared to {}", baseline.display());
+            }
+            for regression in regressions {
+                println!(
+                    "Day {} {} regressed by {:.0}%: {:.1?} -> {:.1?}",
+                    regression.day,
+                    regression.step,
+                    regression.percentage(),
+                    regression.before,
+                    regression.after
+                );
+            }
+        }
+        Err(err) if err.kind() == ErrorKind::NotFound => {
+            if !save {
+                println!(
+                    "No baseline at {}, save one with --save",
+                    baseline.display()
+                );
+            }
+        }
+        Err(err) => {
+            eprintln!("Could not read baseline {}: {}", baseline.display(), err);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    if save {
+        // Days that were not timed this run keep their timings from the previous baseline
+        let mut saved = bench::load_baseline(baseline).unwrap_or_default();
+        saved.extend(timings);
+        if let Err(err) = bench::save_baseline(baseline, &saved) {
+            eprintln!("Could not save baseline {}: {}", baseline.display(), err);
+            return ExitCode::FAILURE;
+        }
+        println!("Saved baseline to {}", baseline.display());
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn batch(day: u8, dir: &Path) -> ExitCode {
+    let files = match batch::files(dir) {
+        Ok(files) => files,
+        Err(err) => {
+            eprintln!("Could not read {}: {}", dir.display(), err);
+            return ExitCode::FAILURE;
+        }
+    };
+    if files.is_empty() {
+        eprintln!("There are no inputs in {}", dir.display());
+        return ExitCode::FAILURE;
+    }
+
+    let outcomes = batch::solve(day, &files);
+    print!("{}", batch::table(&outcomes));
+
+    let failed = outcomes.iter().filter(|o| o.result.is_err()).count();
+    println!("{} solved, {} failed", outcomes.len() - failed, failed);
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn verify(days: Vec<u8>, answers: Option<PathBuf>, record: bool) -> ExitCode {
+    let days = if days.is_empty() {
+        (1..=25).collect()
+    } else {
+        days
+    };
+    let path = answers.unwrap_or_else(answers::default_path);
+    let mut known = match answers::load(&path) {
+        Ok(known) => known,
+        Err(err) => {
+            eprintln!("Could not read {}: {}", path.display(), err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let (mut matching, mut mismatching, mut unknown, mut failed) = (0, 0, 0, 0);
+    for day in days {
+        let input = input::default_path(day);
+        let text = match input::read(&input) {
+            Ok(text) => text,
+            Err(err) => {
+                eprintln!(
+                    "Skipping day {}, could not read {}: {}",
+                    day,
+                    input.display(),
+                    err
+                );
+                continue;
+            }
+        };
+
+        let solved = match days::solve(day, &[1, 2], &text) {
+            Some(Ok(solved)) => solved,
+            Some(Err(err)) => {
+                println!("Day {}: invalid input in {}: {}", day, input.display(), err);
+                failed += 1;
+                continue;
+            }
+            None => continue,
+        };
+
+        let input_sha256 = output::sha256(&text);
+        for (part, (answer, _)) in (1..=2).zip(solved) {
+            if answer == Answer::Empty {
+                // There is nothing to check for a part without a puzzle
+                continue;
+            }
+            let answer = output::answer_to_json(&answer);
+            match known.get(&input_sha256).and_then(|known| known.part(part)) {
+                Some(expected) if *expected == answer => {
+                    println!("Day {} part {}: ok", day, part);
+                    matching += 1;
+                }
+                Some(expected) => {
+                    println!(
+                        "Day {} part {}: MISMATCH, expected {} but got {}",
+                        day, part, expected, answer
+                    );
+                    mismatching += 1;
+                }
+                None => {
+                    println!("Day {} part {}: unknown, got {}", day, part, answer);
+                    unknown += 1;
+                    if record {
+                        let entry = known.entry(input_sha256.clone()).or_default();
+                        entry.day = day;
+                        *entry.part_mut(part) = Some(answer);
+                    }
+                }
+            }
+        }
+    }
+
+    println!(
+        "{} ok, {} mismatching, {} unknown, {} failed",
+        matching, mismatching, unknown, failed
+    );
+
+    if record && unknown > 0 {
+        if let Err(err) = answers::save(&path, &known) {
+            eprintln!("Could not save {}: {}", path.display(), err);
+            return ExitCode::FAILURE;
+        }
+        println!("Recorded {} answers in {}", unknown, path.display());
+    }
+
+    if mismatching > 0 || failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn animate(
+    day: u8,
+    part: u8,
+    input: Option<PathBuf>,
+    fps: f64,
+    cast: Option<PathBuf>,
+    limit: usize,
+) -> ExitCode {
+    if fps.is_nan() || fps <= 0.0 {
+        eprintln!("The frames per second have to be more than 0");
+        return ExitCode::FAILURE;
+    }
+
+    let path = input.unwrap_or_else(|| input::default_path(day));
+    let text = match input::read(&path) {
+        Ok(text) => text,
+        Err(err) => {
+            eprintln!("Could not read {}: {}", path.display(), err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let (solved, recording) = frames::capture(limit, || days::solve(day, &[part], &text));
+    let answer = match solved {
+        Some(Ok(mut answers)) => answers.remove(0).0,
+        Some(Err(err)) => {
+            eprintln!("Invalid input in {}: {}", path.display(), err);
+            return ExitCode::FAILURE;
+        }
+        None => {
+            eprintln!("Day {} is not solved", day);
+            return ExitCode::FAILURE;
+        }
+    };
+    if recording.frames.is_empty() {
+        eprintln!("Day {} part {} does not draw any frames", day, part);
+        return ExitCode::FAILURE;
+    }
+
+    let written = match &cast {
+        Some(cast) => File::create(cast).and_then(|file| {
+            let mut out = BufWriter::new(file);
+            let title = format!("Day {} part {}", day, part);
+            animate::write_cast(&recording, fps, &title, &mut out)?;
+            out.flush()
+        }),
+        None => animate::play(&recording, fps, &mut io::stdout().lock()),
+    };
+    if let Err(err) = written {
+        eprintln!("Could not write the frames: {}", err);
+        return ExitCode::FAILURE;
+    }
+
+    if recording.dropped > 0 {
+        eprintln!(
+            "Showed the first {} frames, {} more were left out",
+            recording.frames.len(),
+            recording.dropped
+        );
+    }
+    if let Some(cast) = cast {
+        eprintln!(
+            "Wrote {} frames to {}",
+            recording.frames.len(),
+            cast.display()
+        );
+    }
+    eprintln!("Part{}: {}", part, answer);
+    ExitCode::SUCCESS
+}
+
+fn watch(day: u8, input: Option<PathBuf>, poll: bool) -> ExitCode {
+    let path = input.unwrap_or_else(|| input::default_path(day));
+    let watch = match watch::Watch::new(day, &path) {
+        Ok(watch) => watch,
+        Err(err) => {
+            eprintln!("Could not watch {}: {}", path.display(), err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match watch::watch(&watch, poll) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Could not watch for changes: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn calories_top(k: usize, input: Option<PathBuf>, format: Format) -> ExitCode {
+    let path = input.unwrap_or_else(|| input::default_path(1));
+    let ranking = match input::open(&path)
+        .map_err(StreamError::Io)
+        .and_then(|reader| day1::top::top_elves(BufReader::new(reader), k))
+    {
+        Ok(ranking) => ranking,
+        Err(StreamError::Io(err)) => {
+            eprintln!("Could not read {}: {}", path.display(), err);
+            return ExitCode::FAILURE;
+        }
+        Err(StreamError::Parse(err)) => {
+            eprintln!("Invalid input in {}: {}", path.display(), err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match format {
+        Format::Text => print!("{}", calories::top_table(&ranking)),
+        Format::Json => println!("{}", calories::top_to_json(&ranking)),
+    }
+    ExitCode::SUCCESS
+}
+
+fn calories_stats(
+    input: Option<PathBuf>,
+    format: ReportFormat,
+    z_score: f64,
+    bins: NonZeroUsize,
+) -> ExitCode {
+    let path = input.unwrap_or_else(|| input::default_path(1));
+    let text = match input::read(&path) {
+        Ok(text) => text,
+        Err(err) => {
+            eprintln!("Could not read {}: {}", path.display(), err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let elfs = match day1::parse(&text) {
+        Ok(elfs) => elfs,
+        Err(err) => {
+            eprintln!("Invalid input in {}: {}", path.display(), err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let Some(report) = day1::stats::report(&elfs, z_score, bins) else {
+        eprintln!("There are no elves in {}", path.display());
+        return ExitCode::FAILURE;
+    };
+
+    match format {
+        ReportFormat::Text => print!("{}", calories::stats_text(&report, z_score)),
+        ReportFormat::Csv => print!("{}", calories::stats_csv(&report, z_score)),
+        ReportFormat::Json => println!("{}", calories::stats_to_json(&report, z_score)),
+    }
+    ExitCode::SUCCESS
+}
+
+fn read_game(rules: &Path) -> Option<Game> {
+    let text = match input::read(rules) {
+        Ok(text) => text,
+        Err(err) => {
+            eprintln!("Could not read {}: {}", rules.display(), err);
+            return None;
+        }
+    };
+    match text.parse() {
+        Ok(game) => Some(game),
+        Err(err) => {
+            eprintln!("Invalid rules in {}: {}", rules.display(), err);
+            None
+        }
+    }
+}
+
+fn guide_play(rules: &Path, input: Option<PathBuf>) -> ExitCode {
+    let Some(game) = read_game(rules) else {
+        return ExitCode::FAILURE;
+    };
+    let path = input.unwrap_or_else(|| input::default_path(2));
+    let text = match input::read(&path) {
+        Ok(text) => text,
+        Err(err) => {
+            eprintln!("Could not read {}: {}", path.display(), err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match day2::play(&game, &text) {
+        Ok(scores) => {
+            for (part, score) in (1..).zip(scores) {
+                println!("Part{}: {}", part, score);
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("Invalid input in {}: {}", path.display(), err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn guide_analyse(rules: Option<PathBuf>, input: Option<PathBuf>, format: Format) -> ExitCode {
+    let game = match rules {
+        Some(rules) => match read_game(&rules) {
+            Some(game) => game,
+            None => return ExitCode::FAILURE,
+        },
+        None => Game::rock_paper_scissors().clone(),
+    };
+    let path = input.unwrap_or_else(|| input::default_path(2));
+    let text = match input::read(&path) {
+        Ok(text) => text,
+        Err(err) => {
+            eprintln!("Could not read {}: {}", path.display(), err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let analysis = match day2::analysis::analyse(&game, &text) {
+        Ok(analysis) => analysis,
+        Err(err) => {
+            eprintln!("Invalid input in {}: {}", path.display(), err.in_day(2));
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match format {
+        Format::Text => print!("{}", guide::analysis_table(&game, &analysis)),
+        Format::Json => println!("{}", guide::analysis_to_json(&game, &analysis)),
+    }
+    ExitCode::SUCCESS
+}
+
+fn generate(day: u8, seed: Option<u64>) -> ExitCode {
+    let seed = seed.unwrap_or_else(|| {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        now.as_nanos() as u64
+    });
+
+    match days::generate(day, seed) {
+        Some(text) => {
+            // The seed goes to stderr, so the input can be piped straight into `run`
+            eprintln!("Seed: {}", seed);
+            println!("{}", text);
+            ExitCode::SUCCESS
+        }
+        None => {
+            eprintln!("Day {} has no input generator", day);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn serve(address: &str) -> ExitCode {
+    let server = match tiny_http::Server::http(address) {
+        Ok(server) => server,
+        Err(err) => {
+            eprintln!("Could not listen on {}: {}", address, err);
+            return ExitCode::FAILURE;
+        }
+    };
+    eprintln!("Listening on http://{}", server.server_addr());
+    serve::serve(server);
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,153 @@
+use clap::ValueEnum;
+use common::budget::{Exceeded, Limit};
+use common::Answer;
+use serde::Serialize;
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use std::io::{self, Read};
+use std::time::Duration;
+
+/// How the runner prints the answers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// `Part1: <answer>`, for people
+    Text,
+    /// A JSON object per part on its own line, for other programs
+    Json,
+}
+
+/// The answer to a part with what is needed to tell where it came from, as printed by
+/// `--format json`.
+#[derive(Debug, Serialize)]
+pub struct Report<'a> {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Value,
+    pub elapsed_ms: f64,
+    pub input_sha256: &'a str,
+}
+
+impl<'a> Report<'a> {
+    pub fn new(
+        day: u8,
+        part: u8,
+        answer: &Answer,
+        elapsed: Duration,
+        input_sha256: &'a str,
+    ) -> Self {
+        Report {
+            day,
+            part,
+            answer: answer_to_json(answer),
+            elapsed_ms: elapsed.as_secs_f64() * 1000.0,
+            input_sha256,
+        }
+    }
+}
+
+/// A part that was stopped for going over its budget, in place of its [`Report`].
+pub fn exceeded_to_json(day: u8, part: u8, exceeded: &Exceeded) -> Value {
+    let limit = match exceeded.limit {
+        Limit::Time(_) => "time",
+        Limit::Memory(_) => "memory",
+    };
+    json!({
+        "day": day,
+        "part": part,
+        "error": "budget exceeded",
+        "limit": limit,
+        "elapsed_ms": exceeded.elapsed.as_secs_f64() * 1000.0,
+        "progress": exceeded.progress,
+    })
+}
+
+/// Numbers stay numbers as long as JSON parsers can read them without losing digits, larger ones
+/// become strings. A screen becomes an array of its rows, and an empty answer `null`.
+pub fn answer_to_json(answer: &Answer) -> Value {
+    // Beyond 2^53 most JSON parsers read numbers as floats and lose digits
+    const MAX_SAFE_INTEGER: i128 = (1 << 53) - 1;
+
+    match answer {
+        Answer::Integer(number) if number.abs() <= MAX_SAFE_INTEGER => Value::from(*number as i64),
+        Answer::Integer(number) => Value::from(number.to_string()),
+        Answer::Text(text) => Value::from(text.as_str()),
+        Answer::Screen(rows) => Value::from(rows.clone()),
+        Answer::Empty => Value::Null,
+    }
+}
+
+/// The SHA-256 of the puzzle input as lowercase hex, to tell which input an answer belongs to.
+pub fn sha256(text: &str) -> String {
+    hex(&Sha256::digest(text.as_bytes()))
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Passes on what is read from another reader, working out the [`sha256`] of the input on the
+/// way, for inputs that are never held in memory as a whole.
+pub struct Sha256Reader<R> {
+    inner: R,
+    hasher: Sha256,
+}
+
+impl<R> Sha256Reader<R> {
+    pub fn new(inner: R) -> Self {
+        Sha256Reader {
+            inner,
+            hasher: Sha256::new(),
+        }
+    }
+
+    /// The SHA-256 of everything that was read.
+    pub fn sha256(self) -> String {
+        hex(&self.hasher.finalize())
+    }
+}
+
+impl<R: Read> Read for Sha256Reader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.hasher.update(&buf[..read]);
+        Ok(read)
+    }
+}
+
+#[test]
+fn test_report() {
+    let report = Report::new(
+        1,
+        2,
+        &Answer::from(206289),
+        Duration::from_micros(1500),
+        "abc",
+    );
+    assert_eq!(
+        serde_json::to_string(&report).unwrap(),
+        r#"{"day":1,"part":2,"answer":206289,"elapsed_ms":1.5,"input_sha256":"abc"}"#
+    );
+
+    assert_eq!(
+        answer_to_json(&Answer::from(u64::MAX)),
+        Value::from("18446744073709551615")
+    );
+    assert_eq!(answer_to_json(&Answer::Empty), Value::Null);
+}
+
+#[test]
+fn test_sha256() {
+    assert_eq!(
+        sha256("abc"),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+}
+
+#[test]
+fn test_sha256_while_reading() {
+    let mut reader = Sha256Reader::new("abc".as_bytes());
+    let mut text = String::new();
+    reader.read_to_string(&mut text).unwrap();
+    assert_eq!(text, "abc");
+    assert_eq!(reader.sha256(), sha256("abc"));
+}
@@ -0,0 +1,133 @@
+use crate::days;
+use crate::output::{self, Report};
+use serde_json::{json, Value};
+use std::io::Read;
+use std::panic::{self, AssertUnwindSafe};
+use std::thread;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+/// The largest puzzle input that is accepted, far above the size of any real input.
+const MAX_INPUT: u64 = 1 << 20;
+
+/// Answers requests until the process is stopped. Every request is solved on a thread of its
+/// own, so a slow day doesn't hold up the others.
+pub fn serve(server: Server) {
+    for request in server.incoming_requests() {
+        thread::spawn(move || respond(request));
+    }
+}
+
+fn respond(mut request: Request) {
+    let mut body = String::new();
+    let read = request
+        .as_reader()
+        .take(MAX_INPUT + 1)
+        .read_to_string(&mut body);
+
+    let (status, json) = match read {
+        Err(err) => (400, error(format!("Could not read the input: {}", err))),
+        Ok(_) if body.len() as u64 > MAX_INPUT => (
+            413,
+            error(format!("The input is larger than {} bytes", MAX_INPUT)),
+        ),
+        Ok(_) => handle(request.method(), request.url(), &body),
+    };
+
+    let content_type = Header::from_bytes("Content-Type", "application/json").unwrap();
+    let response = Response::from_string(json.to_string())
+        .with_status_code(status)
+        .with_header(content_type);
+    if let Err(err) = request.respond(response) {
+        eprintln!("Could not respond to a request: {}", err);
+    }
+}
+
+/// Solves a part for `POST /day/{n}/part/{p}` with the puzzle input as the body. The answer comes
+/// back in the same form as `run --format json`, and anything that goes wrong as an `error`.
+fn handle(method: &Method, url: &str, body: &str) -> (u16, Value) {
+    let path = url.split('?').next().unwrap_or_default();
+    let segments = path.trim_matches('/').split('/').collect::<Vec<_>>();
+    let ["day", day, "part", part] = segments.as_slice() else {
+        return (404, error(format!("Nothing at {}", path)));
+    };
+    if *method != Method::Post {
+        return (
+            405,
+            error("Send the puzzle input to solve with a POST request"),
+        );
+    }
+
+    let day = day.parse::<u8>().ok().filter(|day| (1..=25).contains(day));
+    let part = part
+        .parse::<u8>()
+        .ok()
+        .filter(|part| (1..=2).contains(part));
+    let (Some(day), Some(part)) = (day, part) else {
+        return (404, error(format!("There is no puzzle at {}", path)));
+    };
+
+    // A solution that panics on an unexpected input should not take the server down with it
+    let solved = panic::catch_unwind(AssertUnwindSafe(|| days::solve(day, &[part], body)));
+    match solved {
+        Ok(Some(Ok(mut answers))) => {
+            let (answer, elapsed) = answers.remove(0);
+            let input_sha256 = output::sha256(body);
+            let report = Report::new(day, part, &answer, elapsed, &input_sha256);
+            (200, serde_json::to_value(report).unwrap())
+        }
+        Ok(Some(Err(err))) => (400, error(err.to_string())),
+        Ok(None) => (404, error(format!("Day {} is not solved", day))),
+        Err(_) => (
+            500,
+            error(format!("Day {} part {} failed on this input", day, part)),
+        ),
+    }
+}
+
+fn error(message: impl Into<String>) -> Value {
+    json!({ "error": message.into() })
+}
+
+#[test]
+fn test_routes() {
+    let example = include_str!("../../day1/example.txt");
+
+    let (status, json) = handle(&Method::Post, "/day/1/part/2", example);
+    assert_eq!(status, 200);
+    assert_eq!(json["answer"], json!(45000));
+    assert_eq!(json["part"], json!(2));
+
+    assert_eq!(handle(&Method::Get, "/day/1/part/2", example).0, 405);
+    assert_eq!(handle(&Method::Post, "/day/26/part/1", example).0, 404);
+    assert_eq!(handle(&Method::Post, "/day/1/part/3", example).0, 404);
+    assert_eq!(handle(&Method::Post, "/days", example).0, 404);
+
+    let (status, json) = handle(&Method::Post, "/day/1/part/1", "1000\nsnacks\n");
+    assert_eq!(status, 400);
+    assert!(json["error"].as_str().unwrap().contains("line 2"));
+}
+
+#[test]
+fn test_serves_on_localhost() {
+    use std::io::Write;
+    use std::net::TcpStream;
+
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let address = server.server_addr().to_ip().unwrap();
+    thread::spawn(move || serve(server));
+
+    let body = include_str!("../../day1/example.txt");
+    let mut stream = TcpStream::connect(address).unwrap();
+    write!(
+        stream,
+        "POST /day/1/part/1 HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\nContent-Length: {}\r\n\r\n{}",
+        body.len(),
+        body
+    )
+    .unwrap();
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    assert!(response.starts_with("HTTP/1.1 200"), "{}", response);
+    assert!(response.contains(r#""answer":24000"#), "{}", response);
+}
@@ -0,0 +1,59 @@
+use std::fs::File;
+use std::io::{self, BufWriter, IsTerminal};
+use std::path::Path;
+use tracing_flame::{FlameLayer, FlushGuard};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::prelude::*;
+
+/// What gets logged to stderr for the number of times `-v` was given. Without it only warnings
+/// are, so the output of a quiet run is just the answers.
+fn level(verbose: u8) -> LevelFilter {
+    match verbose {
+        0 => LevelFilter::WARN,
+        1 => LevelFilter::INFO,
+        2 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    }
+}
+
+/// Logs the spans and events of the solutions to stderr, telling how long every span took once
+/// it closes when `verbose` is at least 1. With a `flame` path, every span is also written there
+/// as folded stacks, which `inferno-flamegraph` turns into a flame graph. The file is complete
+/// once the returned guard is dropped.
+pub fn init(
+    verbose: u8,
+    flame: Option<&Path>,
+) -> Result<Option<FlushGuard<BufWriter<File>>>, tracing_flame::Error> {
+    let span_events = if verbose > 0 {
+        FmtSpan::CLOSE
+    } else {
+        FmtSpan::NONE
+    };
+    let log = tracing_subscriber::fmt::layer()
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .with_span_events(span_events)
+        .with_filter(level(verbose));
+
+    let (flame, guard) = match flame {
+        Some(path) => {
+            let (layer, guard) = FlameLayer::with_file(path)?;
+            // The threads of rayon work on the same part, so they count towards the same stacks
+            let layer = layer.with_threads_collapsed(true).with_file_and_line(false);
+            (Some(layer), Some(guard))
+        }
+        None => (None, None),
+    };
+
+    tracing_subscriber::registry().with(log).with(flame).init();
+    Ok(guard)
+}
+
+#[test]
+fn test_verbosity_levels() {
+    assert_eq!(level(0), LevelFilter::WARN);
+    assert_eq!(level(1), LevelFilter::INFO);
+    assert_eq!(level(2), LevelFilter::DEBUG);
+    assert_eq!(level(5), LevelFilter::TRACE);
+}
@@ -0,0 +1,184 @@
+use notify::{Config, PollWatcher, RecursiveMode, Watcher};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Receiver};
+use std::time::Duration;
+use std::{fs, io};
+
+/// How often to look for changes when the file system can't tell us about them.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Editors often write a file in several steps, so changes that follow each other this quickly are
+/// taken together as a single change.
+const SETTLE: Duration = Duration::from_millis(200);
+
+/// What is being watched for a day: its input and the sources of its solution.
+pub struct Watch {
+    pub day: u8,
+    pub input: PathBuf,
+    pub sources: PathBuf,
+}
+
+impl Watch {
+    pub fn new(day: u8, input: &Path) -> io::Result<Self> {
+        // The solutions are run from the root of the workspace, so the paths can't be relative
+        let root = workspace_root();
+        Ok(Watch {
+            day,
+            input: fs::canonicalize(input)?,
+            sources: fs::canonicalize(root.join(format!("day{}/src", day)))?,
+        })
+    }
+
+    fn is_watched(&self, path: &Path) -> bool {
+        path == self.input || path.starts_with(&self.sources)
+    }
+}
+
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+/// Runs both parts of the day now and every time its input or sources change, until the process
+/// is stopped. Uses the notifications of the file system, or polls when there are none or when
+/// `poll` is set.
+pub fn watch(watch: &Watch, poll: bool) -> notify::Result<()> {
+    let (sender, changes) = mpsc::channel();
+
+    // The input is watched through its directory, as editors often replace a file instead of
+    // writing to it, which a watch on the file itself would not survive
+    let input_dir = watch.input.parent().unwrap_or(Path::new("/")).to_path_buf();
+    let native = if poll {
+        None
+    } else {
+        notify::recommended_watcher(sender.clone()).ok()
+    };
+    let mut watcher: Box<dyn Watcher> = match native {
+        Some(watcher) => Box::new(watcher),
+        None => {
+            eprintln!("Polling for changes every {:?}", POLL_INTERVAL);
+            let config = Config::default().with_poll_interval(POLL_INTERVAL);
+            Box::new(PollWatcher::new(sender, config)?)
+        }
+    };
+    watcher.watch(&input_dir, RecursiveMode::NonRecursive)?;
+    watcher.watch(&watch.sources, RecursiveMode::Recursive)?;
+
+    let mut previous = BTreeMap::new();
+    loop {
+        eprintln!("\nRunning day {} on {}", watch.day, watch.input.display());
+        if let Some(answers) = run(watch) {
+            for (part, answer) in &answers {
+                println!("{}", describe(*part, previous.get(part), answer));
+            }
+            previous = answers;
+        }
+        wait_for_change(watch, &changes)?;
+    }
+}
+
+/// Blocks until one of the watched files changes.
+fn wait_for_change(
+    watch: &Watch,
+    changes: &Receiver<notify::Result<notify::Event>>,
+) -> notify::Result<()> {
+    loop {
+        let event = changes.recv().expect("the watcher stopped")?;
+        if event.kind.is_access() || !event.paths.iter().any(|path| watch.is_watched(path)) {
+            continue;
+        }
+        // Let the rest of the change come in before running
+        while changes.recv_timeout(SETTLE).is_ok() {}
+        return Ok(());
+    }
+}
+
+/// Builds the runner with the current sources and runs the day with it. The answers come back per
+/// part, and build errors or an invalid input show up on stderr as they would with `run`.
+fn run(watch: &Watch) -> Option<BTreeMap<u8, (Value, f64)>> {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let output = Command::new(cargo)
+        .args(["run", "--release", "--quiet", "-p", "aoc", "--", "run"])
+        .arg(watch.day.to_string())
+        .arg("--input")
+        .arg(&watch.input)
+        .args(["--format", "json"])
+        .current_dir(workspace_root())
+        .stderr(Stdio::inherit())
+        .output();
+
+    let output = match output {
+        Ok(output) if output.status.success() => output,
+        Ok(_) => return None,
+        Err(err) => {
+            eprintln!("Could not run cargo: {}", err);
+            return None;
+        }
+    };
+
+    let answers = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+        .filter_map(|report| {
+            let part = report["part"].as_u64()? as u8;
+            let elapsed_ms = report["elapsed_ms"].as_f64()?;
+            Some((part, (report["answer"].clone(), elapsed_ms)))
+        })
+        .collect();
+    Some(answers)
+}
+
+/// A line telling the answer to a part and how it compares to the answer of the previous run.
+fn describe(
+    part: u8,
+    previous: Option<&(Value, f64)>,
+    (answer, elapsed_ms): &(Value, f64),
+) -> String {
+    let is_screen = answer.is_array();
+    let notes = match previous {
+        None => format!("{:.1}ms", elapsed_ms),
+        Some((before, _)) if before == answer => format!("unchanged, {:.1}ms", elapsed_ms),
+        // A whole screen doesn't fit in the notes
+        Some(_) if is_screen => format!("changed, {:.1}ms", elapsed_ms),
+        Some((before, _)) => format!("was {}, {:.1}ms", display(before), elapsed_ms),
+    };
+    if is_screen {
+        format!("Part{} ({}):{}", part, notes, display(answer))
+    } else {
+        format!("Part{}: {} ({})", part, display(answer), notes)
+    }
+}
+
+/// Shows an answer as `run` would, with the rows of a screen on lines of their own.
+fn display(answer: &Value) -> String {
+    match answer {
+        Value::String(text) => text.clone(),
+        Value::Array(rows) => rows
+            .iter()
+            .map(|row| format!("\n{}", row.as_str().unwrap_or_default()))
+            .collect(),
+        _ => answer.to_string(),
+    }
+}
+
+#[test]
+fn test_describe_changes() {
+    let answer = |number: i64| (Value::from(number), 1.5);
+    assert_eq!(describe(1, None, &answer(42)), "Part1: 42 (1.5ms)");
+    assert_eq!(
+        describe(2, Some(&answer(42)), &answer(42)),
+        "Part2: 42 (unchanged, 1.5ms)"
+    );
+    assert_eq!(
+        describe(2, Some(&answer(41)), &answer(42)),
+        "Part2: 42 (was 41, 1.5ms)"
+    );
+
+    let screen = |rows: [&str; 2]| (Value::from(rows.to_vec()), 0.5);
+    assert_eq!(
+        describe(2, Some(&screen(["..", ".."])), &screen(["#.", ".#"])),
+        "Part2 (changed, 0.5ms):\n#.\n.#"
+    );
+}
@@ -0,0 +1,64 @@
+use std::fmt::{Display, Formatter};
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    /// A number. Wide enough to hold the answer of every day, whatever integer type it uses.
+    Integer(i128),
+    /// A piece of text, like the crates on top of the stacks of day 5.
+    Text(String),
+    /// The pixels of a screen, one string per row, like the CRT of day 10.
+    Screen(Vec<String>),
+    /// The part has no puzzle to solve, like the second part of day 25.
+    Empty,
+}
+
+impl Answer {
+    /// Whether the answer spans multiple lines when displayed.
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Answer::Screen(rows) if rows.len() > 1)
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Screen(rows) => write!(f, "{}", rows.join("\n")),
+            Answer::Empty => write!(f, "-"),
+        }
+    }
+}
+
+macro_rules! integer_answer {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(number: $ty) -> Self {
+                    Answer::Integer(number as i128)
+                }
+            }
+        )*
+    };
+}
+
+integer_answer!(i32, u32, i64, u64, i128, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+impl From<Vec<String>> for Answer {
+    fn from(rows: Vec<String>) -> Self {
+        Answer::Screen(rows)
+    }
+}
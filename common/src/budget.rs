@@ -0,0 +1,267 @@
+//! Limits on the time and memory a solution may take, so a solution that blows up on an input is
+//! stopped instead of hanging the runner or getting killed for running out of memory.
+//!
+//! Solutions call [`check`] in their long running loops. Once a solution that runs within
+//! [`run`] has spent its budget, the next check stops it by unwinding out of it, which frees
+//! everything it allocated along the way. Outside of [`run`] the checks do nothing.
+//!
+//! A solution with a budget runs on a rayon thread pool of its own, whose threads all know the
+//! budget, so the checks stop it wherever it works in parallel too.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::RefCell;
+use std::fmt::{Display, Formatter};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Once};
+use std::time::{Duration, Instant};
+
+/// How long a solution may run and how much memory it may use. A limit that is `None` is not
+/// enforced.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Budget {
+    pub time: Option<Duration>,
+    /// The most heap memory in bytes the solution may have in use on top of what was in use when
+    /// it started. Only enforced when the program uses the [`CountingAllocator`].
+    pub memory: Option<usize>,
+}
+
+/// The limit of a [`Budget`] that a solution went over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Limit {
+    Time(Duration),
+    Memory(usize),
+}
+
+/// Why a solution was stopped, and how far it got.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Exceeded {
+    pub limit: Limit,
+    pub elapsed: Duration,
+    /// What the solution had done when it was stopped, as told by the check that stopped it.
+    pub progress: String,
+}
+
+impl Display for Exceeded {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.limit {
+            Limit::Time(limit) => write!(f, "budget exceeded, took longer than {:?}", limit)?,
+            Limit::Memory(limit) => write!(
+                f,
+                "budget exceeded, used more than {} MiB after {:.1?}",
+                limit >> 20,
+                self.elapsed
+            )?,
+        }
+        write!(f, " ({})", self.progress)
+    }
+}
+
+struct Active {
+    budget: Budget,
+    start: Instant,
+    deadline: Option<Instant>,
+    /// The memory limit in terms of [`allocated`], so including what was in use at the start.
+    allocated_limit: Option<usize>,
+}
+
+impl Active {
+    fn exceeded(&self) -> Option<Limit> {
+        if let Some(limit) = self.allocated_limit {
+            if allocated() > limit {
+                return self.budget.memory.map(Limit::Memory);
+            }
+        }
+
+        if self
+            .deadline
+            .is_some_and(|deadline| Instant::now() > deadline)
+        {
+            return self.budget.time.map(Limit::Time);
+        }
+        None
+    }
+}
+
+thread_local! {
+    /// The budget of the run the thread works for, set when the threads of its pool start.
+    static ACTIVE: RefCell<Option<Arc<Active>>> = const { RefCell::new(None) };
+}
+
+/// Stops the solution when it has spent its budget. The progress is only asked for then, to tell
+/// how far the solution got.
+pub fn check(progress: impl FnOnce() -> String) {
+    let exceeded = ACTIVE.with(|active| {
+        let active = active.borrow();
+        let active = active.as_ref()?;
+        active
+            .exceeded()
+            .map(|limit| (limit, active.start.elapsed()))
+    });
+
+    if let Some((limit, elapsed)) = exceeded {
+        panic::panic_any(Exceeded {
+            limit,
+            elapsed,
+            progress: progress(),
+        });
+    }
+}
+
+/// Runs `solve` within the budget, or stops it at the first check after the budget is spent.
+/// It runs on as many threads as rayon would have given it. Without any limits it just runs.
+pub fn run<T: Send>(budget: Budget, solve: impl FnOnce() -> T + Send) -> Result<T, Exceeded> {
+    if budget == Budget::default() {
+        return Ok(solve());
+    }
+    quiet_when_exceeded();
+
+    let start = Instant::now();
+    let active = Arc::new(Active {
+        budget,
+        start,
+        deadline: budget.time.map(|time| start + time),
+        allocated_limit: budget.memory.map(|memory| allocated() + memory),
+    });
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(rayon::current_num_threads())
+        .start_handler(move |_| {
+            ACTIVE.with(|thread| thread.replace(Some(Arc::clone(&active))));
+        })
+        .build()
+        .expect("the threads of a budgeted run can be started");
+    let result = pool.install(|| panic::catch_unwind(AssertUnwindSafe(solve)));
+
+    match result {
+        Ok(value) => Ok(value),
+        Err(payload) => match payload.downcast::<Exceeded>() {
+            Ok(exceeded) => Err(*exceeded),
+            Err(payload) => panic::resume_unwind(payload),
+        },
+    }
+}
+
+/// Stopping a solution is not a bug, so it shouldn't be reported like the panic it is made of.
+fn quiet_when_exceeded() {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let report = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !info.payload().is::<Exceeded>() {
+                report(info);
+            }
+        }));
+    });
+}
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+/// The number of bytes on the heap that are in use, as far as the [`CountingAllocator`] knows.
+/// Always 0 when the program doesn't use it.
+pub fn allocated() -> usize {
+    ALLOCATED.load(Ordering::Relaxed)
+}
+
+/// The allocator of the system, keeping count of how much memory is in use so memory budgets can
+/// be enforced. Install it in a binary with `#[global_allocator]`.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc(layout);
+        if !pointer.is_null() {
+            ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed);
+        }
+        pointer
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc_zeroed(layout);
+        if !pointer.is_null() {
+            ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed);
+        }
+        pointer
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        System.dealloc(pointer, layout);
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_pointer = System.realloc(pointer, layout, new_size);
+        if !new_pointer.is_null() {
+            ALLOCATED.fetch_add(new_size, Ordering::Relaxed);
+            ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+        }
+        new_pointer
+    }
+}
+
+#[cfg(test)]
+#[global_allocator]
+static TEST_ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn test_budget_stops_the_solution() {
+    // Checks do nothing outside of a run
+    check(|| unreachable!());
+
+    let unlimited = run(Budget::default(), || {
+        (0..10_000).map(|_| check(String::new)).count()
+    });
+    assert_eq!(unlimited, Ok(10_000));
+
+    let budget = Budget {
+        time: Some(Duration::from_millis(10)),
+        memory: None,
+    };
+    let mut steps = 0;
+    let exceeded = run(budget, || loop {
+        steps += 1;
+        check(|| format!("{} steps", steps));
+    })
+    .unwrap_err();
+    assert_eq!(exceeded.limit, Limit::Time(Duration::from_millis(10)));
+    assert!(exceeded.elapsed >= Duration::from_millis(10));
+    assert_eq!(exceeded.progress, format!("{} steps", steps));
+
+    let budget = Budget {
+        time: None,
+        memory: Some(1 << 20),
+    };
+    let exceeded = run(budget, || {
+        let mut kept = Vec::new();
+        loop {
+            kept.push(vec![0u8; 1024]);
+            check(|| format!("{} KiB kept", kept.len()));
+        }
+    })
+    .unwrap_err();
+    assert_eq!(exceeded.limit, Limit::Memory(1 << 20));
+    assert!(exceeded.progress.ends_with("KiB kept"));
+}
+
+#[test]
+fn test_budget_stops_the_threads_working_in_parallel() {
+    use rayon::prelude::*;
+
+    // Every thread keeps checking for a while, which is well past the budget
+    let budget = Budget {
+        time: Some(Duration::from_millis(10)),
+        memory: None,
+    };
+    let start = Instant::now();
+    let exceeded = run(budget, || {
+        (0..rayon::current_num_threads() * 4)
+            .into_par_iter()
+            .for_each(|task| {
+                while start.elapsed() < Duration::from_secs(2) {
+                    check(|| format!("task {}", task));
+                }
+            })
+    })
+    .unwrap_err();
+    assert_eq!(exceeded.limit, Limit::Time(Duration::from_millis(10)));
+    assert!(exceeded.progress.starts_with("task "));
+    assert!(start.elapsed() < Duration::from_secs(2));
+}
@@ -0,0 +1,124 @@
+//! Frames drawn by the simulations as they run, so a run can be watched back afterwards.
+//!
+//! The days call [`record`] with a closure that draws the current state. Nothing is drawn unless
+//! the run happens inside [`capture`], so the simulations are as fast as before when nobody is
+//! watching.
+use std::cell::RefCell;
+
+/// The frames recorded during a [`capture`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Recording {
+    pub frames: Vec<String>,
+    /// The number of frames that were drawn after the limit was reached, and were not kept.
+    pub dropped: usize,
+}
+
+impl Recording {
+    /// The number of columns and rows needed to show any of the frames.
+    pub fn size(&self) -> (usize, usize) {
+        let width = self
+            .frames
+            .iter()
+            .flat_map(|frame| frame.lines())
+            .map(visible_width)
+            .max()
+            .unwrap_or(0);
+        let height = self
+            .frames
+            .iter()
+            .map(|frame| frame.lines().count())
+            .max()
+            .unwrap_or(0);
+        (width, height)
+    }
+}
+
+/// The number of characters of a line that take up a column, leaving out colour codes.
+fn visible_width(line: &str) -> usize {
+    let mut width = 0;
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // Skip up to and including the letter that ends the escape sequence
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            width += 1;
+        }
+    }
+    width
+}
+
+struct Recorder {
+    recording: Recording,
+    limit: usize,
+}
+
+thread_local! {
+    static RECORDER: RefCell<Option<Recorder>> = const { RefCell::new(None) };
+}
+
+/// Whether the frames that are drawn now are kept.
+pub fn is_recording() -> bool {
+    RECORDER.with(|recorder| recorder.borrow().is_some())
+}
+
+/// Draws a frame and keeps it, if a [`capture`] is running on this thread and its limit has not
+/// been reached yet.
+pub fn record(draw: impl FnOnce() -> String) {
+    let keep = RECORDER.with(|recorder| match recorder.borrow_mut().as_mut() {
+        Some(recorder) if recorder.recording.frames.len() < recorder.limit => true,
+        Some(recorder) => {
+            recorder.recording.dropped += 1;
+            false
+        }
+        None => false,
+    });
+    if keep {
+        // Drawn outside of the borrow, so drawing can't get in the way of the recorder
+        let frame = draw();
+        RECORDER.with(|recorder| {
+            if let Some(recorder) = recorder.borrow_mut().as_mut() {
+                recorder.recording.frames.push(frame);
+            }
+        });
+    }
+}
+
+/// Runs `run` and returns the frames it drew, keeping at most `limit` of them.
+pub fn capture<T>(limit: usize, run: impl FnOnce() -> T) -> (T, Recording) {
+    let outer = RECORDER.with(|recorder| {
+        recorder.replace(Some(Recorder {
+            recording: Recording::default(),
+            limit,
+        }))
+    });
+    let result = run();
+    let recorder = RECORDER.with(|recorder| recorder.replace(outer));
+    (result, recorder.map(|r| r.recording).unwrap_or_default())
+}
+
+#[test]
+fn test_capture_keeps_frames_up_to_the_limit() {
+    // Not drawn at all outside of a capture
+    record(|| unreachable!());
+    assert!(!is_recording());
+
+    let (answer, recording) = capture(2, || {
+        for i in 0..5 {
+            record(|| format!("frame {}\n\x1b[31m#\x1b[0m.", i));
+        }
+        42
+    });
+    assert_eq!(answer, 42);
+    assert_eq!(
+        recording.frames,
+        vec!["frame 0\n\x1b[31m#\x1b[0m.", "frame 1\n\x1b[31m#\x1b[0m."]
+    );
+    assert_eq!(recording.dropped, 3);
+    assert_eq!(recording.size(), (7, 2));
+    assert!(!is_recording());
+}
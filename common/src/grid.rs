@@ -0,0 +1,345 @@
+use crate::parse::lines_with;
+use crate::{ParseError, Point};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+/// A rectangular grid with a tile on every point, like a heightmap or the trees of day 8.
+///
+/// The top left tile is at `(0, 0)`. Points outside of the grid have no tile, so [`Grid::get`]
+/// doubles as a bounds check.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    tiles: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid filled with the given tile.
+    pub fn new(width: usize, height: usize, tile: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            tiles: vec![tile; width * height],
+        }
+    }
+
+    /// A grid from its rows, which all have to be as wide as the first one.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, ParseError> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+
+        let mut tiles = Vec::with_capacity(width * height);
+        for (index, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(ParseError::expected(format!("{} tiles", width)).offset_line(index));
+            }
+            tiles.extend(row);
+        }
+
+        Ok(Grid {
+            width,
+            height,
+            tiles,
+        })
+    }
+
+    /// Parses a grid with one tile per character. Characters for which `tile` returns `None` are
+    /// reported as an error, with `expected` describing the valid characters.
+    pub fn parse(
+        text: &str,
+        expected: &str,
+        mut tile: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let rows = lines_with(text, |line| {
+            line.chars()
+                .enumerate()
+                .map(|(column, c)| {
+                    tile(c).ok_or_else(|| ParseError::expected(expected).at_column(column + 1))
+                })
+                .collect()
+        })?;
+        Grid::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether the point lies within the grid.
+    pub fn contains(&self, point: Point) -> bool {
+        self.index_of(point).is_some()
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|index| &self.tiles[index])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(|index| &mut self.tiles[index])
+    }
+
+    /// The tiles of a single row.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.tiles[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// All points of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (width, height) = (self.width as i32, self.height as i32);
+        (0..height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// All tiles with their point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.tiles.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        self.points().zip(self.tiles.iter_mut())
+    }
+
+    /// The point of the first tile, row by row, that matches the predicate.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, tile)| predicate(tile))
+            .map(|(point, _)| point)
+    }
+
+    /// The points directly next to the given point that lie within the grid.
+    pub fn neighbours(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point
+            .neighbours()
+            .filter(move |neighbour| self.contains(*neighbour))
+    }
+
+    /// A grid of the same size with every tile mapped.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            tiles: self.tiles.iter().map(f).collect(),
+        }
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        let x = usize::try_from(point.x).ok().filter(|x| *x < self.width)?;
+        let y = usize::try_from(point.y).ok().filter(|y| *y < self.height)?;
+        Some(y * self.width + x)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{:?} lies outside of the grid", point))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{:?} lies outside of the grid", point))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for tile in row {
+                write!(f, "{}", tile)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A grid that only stores the points that have a tile, for grids that are mostly empty or that
+/// grow in any direction, like falling sand or spreading elves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    tiles: HashMap<Point, T>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        SparseGrid {
+            tiles: HashMap::new(),
+        }
+    }
+
+    /// Parses a grid with one tile per character, leaving the points with the `empty` character
+    /// out. Other characters for which `tile` returns `None` are reported as an error, with
+    /// `expected` describing the valid characters.
+    pub fn parse(
+        text: &str,
+        empty: char,
+        expected: &str,
+        mut tile: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut grid = SparseGrid::new();
+        for (y, line) in text.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                if c == empty {
+                    continue;
+                }
+                let tile = tile(c).ok_or_else(|| {
+                    ParseError::expected(expected)
+                        .offset_line(y)
+                        .at_column(x + 1)
+                })?;
+                grid.insert(Point::new(x as i32, y as i32), tile);
+            }
+        }
+        Ok(grid)
+    }
+
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    /// Whether there is a tile at the point.
+    pub fn contains(&self, point: Point) -> bool {
+        self.tiles.contains_key(&point)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.tiles.get(&point)
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.tiles.get_mut(&point)
+    }
+
+    /// Puts a tile at the point, returning the tile that was there before.
+    pub fn insert(&mut self, point: Point, tile: T) -> Option<T> {
+        self.tiles.insert(point, tile)
+    }
+
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        self.tiles.remove(&point)
+    }
+
+    /// The points that have a tile, in no particular order.
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.tiles.keys().copied()
+    }
+
+    /// The tiles with their point, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.tiles.iter().map(|(point, tile)| (*point, tile))
+    }
+
+    /// The top left and bottom right corners of the smallest rectangle that holds every tile.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        let mut points = self.points();
+        let first = points.next()?;
+        Some(points.fold((first, first), |(min, max), point| {
+            (
+                Point::new(min.x.min(point.x), min.y.min(point.y)),
+                Point::new(max.x.max(point.x), max.y.max(point.y)),
+            )
+        }))
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid::new()
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        SparseGrid {
+            tiles: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> Extend<(Point, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (Point, T)>>(&mut self, iter: I) {
+        self.tiles.extend(iter)
+    }
+}
+
+/// Draws the tiles within the bounds of the grid, with a `.` for the points without a tile.
+impl<T: Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Some((min, max)) = self.bounds() else {
+            return Ok(());
+        };
+
+        for y in min.y..=max.y {
+            if y > min.y {
+                writeln!(f)?;
+            }
+            for x in min.x..=max.x {
+                match self.get(Point::new(x, y)) {
+                    Some(tile) => write!(f, "{}", tile)?,
+                    None => write!(f, ".")?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_parse_and_display() {
+    let grid = Grid::parse("123\n456", "<digit>", |c| c.to_digit(10)).unwrap();
+    assert_eq!(grid.width(), 3);
+    assert_eq!(grid.height(), 2);
+    assert_eq!(grid[Point::new(2, 1)], 6);
+    assert_eq!(grid.get(Point::new(3, 0)), None);
+    assert_eq!(grid.get(Point::new(0, -1)), None);
+    assert_eq!(grid.to_string(), "123\n456");
+
+    let err = Grid::parse("123\n4x6", "<digit>", |c| c.to_digit(10)).unwrap_err();
+    assert_eq!(err.line(), Some(2));
+    assert_eq!(err.column(), Some(2));
+
+    let err = Grid::parse("123\n45", "<digit>", |c| c.to_digit(10)).unwrap_err();
+    assert_eq!(err.to_string(), "line 2: expected \"3 tiles\"");
+}
+
+#[test]
+fn test_neighbours_stay_within_the_grid() {
+    let grid = Grid::new(3, 2, 0);
+    assert_eq!(grid.neighbours(Point::new(0, 0)).count(), 2);
+    assert_eq!(grid.neighbours(Point::new(1, 0)).count(), 3);
+    assert_eq!(grid.neighbours(Point::new(1, 1)).count(), 3);
+}
+
+#[test]
+fn test_sparse_grid() {
+    let mut grid =
+        SparseGrid::parse("..#\n#..", '.', "# or .", |c| (c == '#').then_some('#')).unwrap();
+    assert_eq!(grid.len(), 2);
+    assert!(grid.contains(Point::new(2, 0)));
+
+    grid.insert(Point::new(-1, 2), 'o');
+    assert_eq!(grid.bounds(), Some((Point::new(-1, 0), Point::new(2, 2))));
+    assert_eq!(grid.to_string(), "...#\n.#..\no...");
+}
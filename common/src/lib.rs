@@ -0,0 +1,15 @@
+mod answer;
+pub mod budget;
+pub mod frames;
+mod grid;
+pub mod parse;
+mod point;
+pub mod search;
+mod solution;
+pub mod stream;
+
+pub use answer::Answer;
+pub use grid::{Grid, SparseGrid};
+pub use parse::ParseError;
+pub use point::{Direction, Point};
+pub use solution::{parse_input, Solution};
@@ -0,0 +1,206 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// An error in the puzzle input, pointing at the line and column where parsing went wrong.
+///
+/// Parsers of a single line only know the column, so the line is filled in by [`lines`] and
+/// [`blocks`] and the day by the runner. Lines and columns are 1-based, like in an editor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    day: Option<u8>,
+    line: Option<usize>,
+    column: Option<usize>,
+    expected: String,
+}
+
+impl ParseError {
+    /// An error for input that did not match what was expected, like `"flow rate=<n>"`.
+    pub fn expected(expected: impl Into<String>) -> Self {
+        ParseError {
+            day: None,
+            line: None,
+            column: None,
+            expected: expected.into(),
+        }
+    }
+
+    /// Points the error at `token`, which must be a slice of `line`, to find its column.
+    /// An error that already has a column is taken to be relative to the token, so errors of
+    /// nested parsers end up pointing at the right column of the whole line.
+    pub fn at(mut self, line: &str, token: &str) -> Self {
+        let start = line.as_ptr() as usize;
+        let position = token.as_ptr() as usize;
+        if position >= start && position <= start + line.len() {
+            let column = line[..position - start].chars().count() + 1;
+            self.column = Some(column + self.column.map_or(0, |inner| inner - 1));
+        }
+        self
+    }
+
+    /// Sets the column of the error.
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column = Some(column);
+        self
+    }
+
+    /// Moves the error down by `lines`, for errors of a line or block that starts further down
+    /// the input. An error without a line is assumed to be on the first line.
+    pub fn offset_line(mut self, lines: usize) -> Self {
+        self.line = Some(self.line.unwrap_or(1) + lines);
+        self
+    }
+
+    /// Sets the day the error occurred in.
+    pub fn in_day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
+
+    pub fn expected_token(&self) -> &str {
+        &self.expected
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut location = Vec::new();
+        if let Some(day) = self.day {
+            location.push(format!("day{}", day));
+        }
+        if let Some(line) = self.line {
+            location.push(format!("line {}", line));
+        }
+        if let Some(column) = self.column {
+            location.push(format!("column {}", column));
+        }
+
+        if !location.is_empty() {
+            write!(f, "{}: ", location.join(" "))?;
+        }
+        write!(f, "expected {:?}", self.expected)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses every line of the text, reporting errors on the line they occurred on.
+pub fn lines<T>(text: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    lines_with(text, str::parse)
+}
+
+/// Parses every line of the text with the given parser, reporting errors on the line they
+/// occurred on.
+pub fn lines_with<T>(
+    text: &str,
+    mut parse: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    text.lines()
+        .enumerate()
+        .map(|(index, line)| parse(line).map_err(|err| err.offset_line(index)))
+        .collect()
+}
+
+/// Parses every block of lines separated by an empty line, reporting errors on the line of the
+/// whole text they occurred on.
+pub fn blocks<T>(text: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    blocks_with(text, str::parse)
+}
+
+/// Parses every block of lines separated by an empty line with the given parser, reporting
+/// errors on the line of the whole text they occurred on.
+pub fn blocks_with<T>(
+    text: &str,
+    mut parse: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    let mut offset = 0;
+    text.split("\n\n")
+        .map(|block| {
+            let parsed = parse(block).map_err(|err| err.offset_line(offset));
+            offset += block.lines().count() + 1;
+            parsed
+        })
+        .collect()
+}
+
+/// Parses a number from `token`, a slice of `line`.
+pub fn number<T: FromStr>(line: &str, token: &str, expected: &str) -> Result<T, ParseError> {
+    token
+        .trim()
+        .parse()
+        .map_err(|_| ParseError::expected(expected).at(line, token))
+}
+
+#[test]
+fn test_error_message() {
+    let err = ParseError::expected("flow rate=<n>")
+        .offset_line(6)
+        .in_day(16);
+    assert_eq!(err.to_string(), "day16 line 7: expected \"flow rate=<n>\"");
+
+    let err = ParseError::expected("<n>").at_column(3);
+    assert_eq!(err.to_string(), "column 3: expected \"<n>\"");
+}
+
+#[test]
+fn test_nested_columns_are_relative_to_the_token() {
+    let line = "a=1, b=x";
+    let token = &line[5..];
+    let err = number::<u32>(token, &token[2..], "<n>").unwrap_err();
+    assert_eq!(err.at(line, token).column(), Some(8));
+}
+
+#[test]
+fn test_lines_report_line_and_column() {
+    let err = lines::<Number>("1\n2\n3 x").unwrap_err();
+    assert_eq!(err.line(), Some(3));
+    assert_eq!(err.column(), Some(1));
+}
+
+#[test]
+fn test_blocks_report_line_of_whole_text() {
+    #[derive(Debug)]
+    struct Block;
+
+    impl FromStr for Block {
+        type Err = ParseError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            lines::<Number>(s).map(|_| Block)
+        }
+    }
+
+    let err = blocks::<Block>("1\n2\n\n3\nx").unwrap_err();
+    assert_eq!(err.line(), Some(5));
+}
+
+#[cfg(test)]
+#[derive(Debug)]
+struct Number;
+
+#[cfg(test)]
+impl FromStr for Number {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        number::<u32>(s, s, "<n>").map(|_| Number)
+    }
+}
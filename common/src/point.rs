@@ -0,0 +1,148 @@
+use std::ops::{Add, AddAssign, Sub};
+use Direction::{Down, Left, Right, Up};
+
+/// A point on a grid. `x` grows to the right and `y` grows downwards, like the lines and columns
+/// of the puzzle input.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub const fn new(x: i32, y: i32) -> Self {
+        Point { x, y }
+    }
+
+    /// The point one step away in the given direction.
+    pub fn step(self, direction: Direction) -> Point {
+        self + direction.offset()
+    }
+
+    /// The 4 points directly next to this point, in the order of [`Direction::ALL`].
+    pub fn neighbours(self) -> impl Iterator<Item = Point> {
+        Direction::ALL.into_iter().map(move |d| self.step(d))
+    }
+
+    /// The 8 points around this point, including the diagonal ones, row by row.
+    pub fn adjacent(self) -> impl Iterator<Item = Point> {
+        (-1..=1)
+            .flat_map(|y| (-1..=1).map(move |x| Point::new(x, y)))
+            .filter(|offset| *offset != Point::default())
+            .map(move |offset| self + offset)
+    }
+
+    pub fn manhattan_distance(self, other: Point) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+/// One of the four directions on a grid, as seen on the puzzle input: up is towards the first line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions, clockwise starting from up.
+    pub const ALL: [Direction; 4] = [Up, Right, Down, Left];
+
+    /// The step to take to move one tile in this direction.
+    pub fn offset(self) -> Point {
+        match self {
+            Up => Point::new(0, -1),
+            Right => Point::new(1, 0),
+            Down => Point::new(0, 1),
+            Left => Point::new(-1, 0),
+        }
+    }
+
+    /// The direction after turning 90 degrees counterclockwise.
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Up => Left,
+            Right => Up,
+            Down => Right,
+            Left => Down,
+        }
+    }
+
+    /// The direction after turning 90 degrees clockwise.
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Up => Right,
+            Right => Down,
+            Down => Left,
+            Left => Up,
+        }
+    }
+
+    pub fn opposite(self) -> Direction {
+        match self {
+            Up => Down,
+            Right => Left,
+            Down => Up,
+            Left => Right,
+        }
+    }
+}
+
+#[test]
+fn test_neighbours() {
+    let point = Point::new(2, 5);
+    let neighbours: Vec<Point> = point.neighbours().collect();
+    assert_eq!(
+        neighbours,
+        vec![
+            Point::new(2, 4),
+            Point::new(3, 5),
+            Point::new(2, 6),
+            Point::new(1, 5)
+        ]
+    );
+
+    let adjacent: Vec<Point> = point.adjacent().collect();
+    assert_eq!(adjacent.len(), 8);
+    assert!(!adjacent.contains(&point));
+    assert!(adjacent.contains(&Point::new(1, 4)));
+    assert!(adjacent.contains(&Point::new(3, 6)));
+}
+
+#[test]
+fn test_turning() {
+    for direction in Direction::ALL {
+        assert_eq!(direction.turn_left().turn_right(), direction);
+        assert_eq!(direction.turn_right().turn_right(), direction.opposite());
+        assert_eq!(
+            direction.offset() + direction.opposite().offset(),
+            Point::default()
+        );
+    }
+    assert_eq!(Up.turn_right(), Right);
+    assert_eq!(Up.turn_left(), Left);
+}
@@ -0,0 +1,345 @@
+//! Searches over a graph of states that is only known through a successor function, like the
+//! squares of a heightmap or the moves of a robot factory.
+use crate::budget;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// A path from one of the starts of a search to a goal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S> {
+    /// The states along the path, from the start up to and including the goal.
+    pub states: Vec<S>,
+    /// The total cost of the path. For a breadth-first search this is the number of steps.
+    pub cost: u32,
+}
+
+impl<S> Path<S> {
+    pub fn start(&self) -> &S {
+        &self.states[0]
+    }
+
+    pub fn goal(&self) -> &S {
+        &self.states[self.states.len() - 1]
+    }
+
+    /// The number of steps taken, which is one less than the number of states.
+    pub fn steps(&self) -> usize {
+        self.states.len() - 1
+    }
+}
+
+/// The outcome of a search: the path to the goal if there is one, and how much of the graph was
+/// explored to find it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Search<S> {
+    pub path: Option<Path<S>>,
+    /// The number of distinct states that were reached.
+    pub visited: usize,
+    /// The number of states of which the successors were generated.
+    pub expanded: usize,
+}
+
+impl<S> Search<S> {
+    /// The cost of the path to the goal, if there is one.
+    pub fn cost(&self) -> Option<u32> {
+        self.path.as_ref().map(|path| path.cost)
+    }
+}
+
+/// Breadth-first search from any of the starts to the closest state that is a goal, where every
+/// step costs 1.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut parents: HashMap<S, Option<S>> = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !parents.contains_key(&start) {
+            parents.insert(start.clone(), None);
+            queue.push_back((start, 0));
+        }
+    }
+
+    let mut expanded = 0;
+    while let Some((state, cost)) = queue.pop_front() {
+        budget::check(|| format!("{} states visited", parents.len()));
+        if is_goal(&state) {
+            let states = reconstruct(state, |state| parents[state].clone());
+            return Search {
+                path: Some(Path { states, cost }),
+                visited: parents.len(),
+                expanded,
+            };
+        }
+
+        expanded += 1;
+        for next in successors(&state) {
+            if !parents.contains_key(&next) {
+                parents.insert(next.clone(), Some(state.clone()));
+                queue.push_back((next, cost + 1));
+            }
+        }
+    }
+
+    Search {
+        path: None,
+        visited: parents.len(),
+        expanded,
+    }
+}
+
+/// Dijkstra's algorithm from any of the starts to the cheapest state that is a goal. The
+/// successors come with the cost of the step towards them.
+pub fn dijkstra<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u32)>,
+{
+    astar(starts, successors, |_| 0, is_goal)
+}
+
+/// A* search from any of the starts to the cheapest state that is a goal. The successors come
+/// with the cost of the step towards them.
+///
+/// The heuristic estimates the cost from a state to the closest goal. The path is only the
+/// cheapest one when the heuristic never overestimates that cost.
+pub fn astar<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> u32,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u32)>,
+{
+    // The cheapest known cost of every reached state, and the state it was reached from
+    let mut best: HashMap<S, (u32, Option<S>)> = HashMap::new();
+    // The open list refers to the states by their index, so they don't have to be `Ord`
+    let mut states = Vec::new();
+    let mut open = BinaryHeap::new();
+
+    for start in starts {
+        if !best.contains_key(&start) {
+            best.insert(start.clone(), (0, None));
+            open.push(Reverse((heuristic(&start), 0, states.len())));
+            states.push(start);
+        }
+    }
+
+    let mut expanded = 0;
+    while let Some(Reverse((_, cost, index))) = open.pop() {
+        budget::check(|| format!("{} states visited", best.len()));
+        let state = states[index].clone();
+        if cost > best[&state].0 {
+            // A cheaper way to this state was found after it was put on the open list
+            continue;
+        }
+
+        if is_goal(&state) {
+            let states = reconstruct(state, |state| best[state].1.clone());
+            return Search {
+                path: Some(Path { states, cost }),
+                visited: best.len(),
+                expanded,
+            };
+        }
+
+        expanded += 1;
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            if best.get(&next).is_none_or(|(known, _)| next_cost < *known) {
+                best.insert(next.clone(), (next_cost, Some(state.clone())));
+                open.push(Reverse((
+                    next_cost + heuristic(&next),
+                    next_cost,
+                    states.len(),
+                )));
+                states.push(next);
+            }
+        }
+    }
+
+    Search {
+        path: None,
+        visited: best.len(),
+        expanded,
+    }
+}
+
+/// All states that can be reached from any of the starts, including the starts themselves.
+pub fn reachable<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+) -> HashSet<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut visited = HashSet::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if visited.insert(start.clone()) {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(state) = queue.pop_front() {
+        budget::check(|| format!("{} states visited", visited.len()));
+        for next in successors(&state) {
+            if visited.insert(next.clone()) {
+                queue.push_back(next);
+            }
+        }
+    }
+    visited
+}
+
+/// The best state found by [`branch_and_bound`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Best<S> {
+    pub state: S,
+    pub value: u32,
+    /// The number of states that were expanded.
+    pub visited: usize,
+    /// The number of states that were skipped because they could not beat the best state.
+    pub pruned: usize,
+}
+
+/// Depth-first search for the state with the highest value, for graphs without cycles.
+///
+/// The bound of a state is an upper limit on the value of that state and of every state that can
+/// be reached from it. States whose bound does not exceed the best value found so far are skipped
+/// with everything that follows them, so the tighter the bound, the less there is to search.
+/// Successors are explored in reverse order, so the most promising one should come last.
+pub fn branch_and_bound<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut value: impl FnMut(&S) -> u32,
+    mut bound: impl FnMut(&S) -> u32,
+) -> Best<S>
+where
+    S: Clone,
+    I: IntoIterator<Item = S>,
+{
+    let mut best = Best {
+        value: value(&start),
+        state: start.clone(),
+        visited: 0,
+        pruned: 0,
+    };
+
+    let mut stack = vec![start];
+    while let Some(state) = stack.pop() {
+        budget::check(|| {
+            format!(
+                "{} states visited, best so far {}",
+                best.visited, best.value
+            )
+        });
+        if bound(&state) <= best.value {
+            best.pruned += 1;
+            continue;
+        }
+        best.visited += 1;
+
+        let state_value = value(&state);
+        if state_value > best.value {
+            best.value = state_value;
+            best.state = state.clone();
+        }
+        stack.extend(successors(&state));
+    }
+    best
+}
+
+/// Walks back from the goal to the start along the parents of the states.
+fn reconstruct<S>(goal: S, mut parent: impl FnMut(&S) -> Option<S>) -> Vec<S> {
+    let mut states = vec![goal];
+    while let Some(previous) = parent(&states[states.len() - 1]) {
+        states.push(previous);
+    }
+    states.reverse();
+    states
+}
+
+#[test]
+fn test_bfs_finds_the_shortest_path() {
+    // Steps of +1 and *2 from 1 to 10: 1, 2, 4, 5, 10
+    let search = bfs([1], |n: &u32| [n + 1, n * 2], |n| *n == 10);
+    let path = search.path.unwrap();
+    assert_eq!(path.states, vec![1, 2, 4, 5, 10]);
+    assert_eq!(path.cost, 4);
+    assert_eq!(path.steps(), 4);
+    assert!(search.visited >= search.expanded);
+
+    let search = bfs([1], |n: &u32| (*n < 5).then_some(n + 1), |n| *n == 10);
+    assert_eq!(search.path, None);
+    assert_eq!(search.visited, 5);
+}
+
+#[test]
+fn test_dijkstra_and_astar_find_the_cheapest_path() {
+    // Going straight to 3 is more expensive than taking the detour over 2
+    let successors = |n: &u32| match n {
+        0 => vec![(1, 1), (3, 10)],
+        1 => vec![(2, 1)],
+        2 => vec![(3, 1)],
+        _ => vec![],
+    };
+    let search = dijkstra([0], successors, |n| *n == 3);
+    assert_eq!(search.path.unwrap().states, vec![0, 1, 2, 3]);
+
+    let search = astar([0], successors, |n| 3 - n, |n| *n == 3);
+    assert_eq!(search.cost(), Some(3));
+}
+
+#[test]
+fn test_reachable() {
+    let states = reachable([0, 10], |n: &u32| (n % 10 < 4).then_some(n + 1));
+    assert_eq!(states.len(), 10);
+    assert!(states.contains(&14));
+    assert!(!states.contains(&5));
+}
+
+#[test]
+fn test_branch_and_bound_prunes() {
+    // Pick items worth 6, 5 and 4 with weights 3, 2 and 2 to fit a weight of 4
+    let items = [(6, 3), (5, 2), (4, 2)];
+    let successors = |(index, value, weight): &(usize, u32, u32)| {
+        let (index, value, weight) = (*index, *value, *weight);
+        let mut next = vec![];
+        if let Some((item_value, item_weight)) = items.get(index) {
+            next.push((index + 1, value, weight));
+            if weight + item_weight <= 4 {
+                next.push((index + 1, value + item_value, weight + item_weight));
+            }
+        }
+        next
+    };
+    let remaining = |index: usize| {
+        items[index.min(items.len())..]
+            .iter()
+            .map(|i| i.0)
+            .sum::<u32>()
+    };
+    let best = branch_and_bound(
+        (0, 0, 0),
+        successors,
+        |state| state.1,
+        |state| state.1 + remaining(state.0),
+    );
+    assert_eq!(best.value, 9);
+    assert!(best.pruned > 0);
+}
@@ -0,0 +1,50 @@
+use crate::{Answer, ParseError};
+
+/// The solution of a single day.
+///
+/// The puzzle input is parsed once into [`Solution::Input`], after which both parts work on
+/// that parsed input. Parts that need to mutate the input, like simulations, work on a copy.
+pub trait Solution {
+    /// The day of the puzzle.
+    const DAY: u8;
+
+    /// The parsed puzzle input, shared by both parts.
+    type Input;
+
+    /// Parse the puzzle input.
+    fn parse(text: &str) -> Result<Self::Input, ParseError>;
+
+    /// Solve the first part of the puzzle.
+    fn part1(input: &Self::Input) -> Answer;
+
+    /// Solve the second part of the puzzle.
+    fn part2(input: &Self::Input) -> Answer;
+}
+
+/// Parses the puzzle input of a day, tagging a parse error with that day.
+pub fn parse_input<S: Solution>(text: &str) -> Result<S::Input, ParseError> {
+    S::parse(text).map_err(|err| err.in_day(S::DAY))
+}
+
+/// Exposes a day as plain functions on the puzzle text, so other crates can call it without
+/// going through [`Solution`]: `parse`, plus `part1` and `part2` which parse the text and solve
+/// a part in one go.
+#[macro_export]
+macro_rules! solution_functions {
+    ($day:ty) => {
+        /// Parses the puzzle input.
+        pub fn parse(text: &str) -> Result<<$day as $crate::Solution>::Input, $crate::ParseError> {
+            $crate::parse_input::<$day>(text)
+        }
+
+        /// Parses the puzzle input and solves the first part.
+        pub fn part1(text: &str) -> Result<$crate::Answer, $crate::ParseError> {
+            parse(text).map(|input| <$day as $crate::Solution>::part1(&input))
+        }
+
+        /// Parses the puzzle input and solves the second part.
+        pub fn part2(text: &str) -> Result<$crate::Answer, $crate::ParseError> {
+            parse(text).map(|input| <$day as $crate::Solution>::part2(&input))
+        }
+    };
+}
@@ -0,0 +1,129 @@
+//! Solving a day while its input is read a line at a time, so inputs far larger than memory can
+//! be piped through it.
+//!
+//! Days whose input is a list of lines implement [`Streaming`] next to [`Solution`]: every line is
+//! fed to them as it is read, and they keep only what they need of it to answer both parts once
+//! the input ends.
+use crate::{budget, Answer, ParseError, Solution};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::{self, BufRead};
+
+/// A day that can be solved while its input is read line by line.
+pub trait Streaming: Solution {
+    /// What is kept of the input read so far.
+    type State: Default;
+
+    /// Takes in the next line of the input, without its line ending. Errors only need the
+    /// column, the line is filled in by [`solve`].
+    fn feed(state: &mut Self::State, line: &str) -> Result<(), ParseError>;
+
+    /// Solves both parts once the whole input has been fed.
+    fn finish(state: Self::State) -> Result<[Answer; 2], ParseError>;
+}
+
+/// Why a streamed input could not be solved.
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl Display for StreamError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StreamError::Io(err) => write!(f, "could not read the input: {}", err),
+            StreamError::Parse(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(err: io::Error) -> Self {
+        StreamError::Io(err)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(err: ParseError) -> Self {
+        StreamError::Parse(err)
+    }
+}
+
+/// Reads the input a line at a time, feeding every line to the day, and solves both parts at the
+/// end. Parse errors are tagged with the line and the day, like those of [`crate::parse_input`].
+pub fn solve<S: Streaming>(reader: impl BufRead) -> Result<[Answer; 2], StreamError> {
+    let mut state = S::State::default();
+    feed::<S>(reader, &mut state)?;
+    Ok(S::finish(state).map_err(|err| err.in_day(S::DAY))?)
+}
+
+/// Feeds every line of the input to the given state, for days that do more with what they keep of
+/// the input than answering the parts.
+pub fn feed<S: Streaming>(
+    mut reader: impl BufRead,
+    state: &mut S::State,
+) -> Result<(), StreamError> {
+    let mut line = String::new();
+    let mut index = 0;
+    while reader.read_line(&mut line)? > 0 {
+        budget::check(|| format!("{} lines read", index));
+        // Line endings are left out like `str::lines` does
+        let content = line.strip_suffix('\n').unwrap_or(&line);
+        let content = content.strip_suffix('\r').unwrap_or(content);
+        S::feed(state, content).map_err(|err| err.offset_line(index).in_day(S::DAY))?;
+        line.clear();
+        index += 1;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+struct Sum;
+
+#[cfg(test)]
+impl Solution for Sum {
+    const DAY: u8 = 1;
+
+    type Input = Vec<i64>;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        crate::parse::lines_with(text, |line| crate::parse::number(line, line, "<n>"))
+    }
+
+    fn part1(numbers: &Self::Input) -> Answer {
+        numbers.iter().sum::<i64>().into()
+    }
+
+    fn part2(numbers: &Self::Input) -> Answer {
+        numbers.len().into()
+    }
+}
+
+#[cfg(test)]
+impl Streaming for Sum {
+    type State = (i64, usize);
+
+    fn feed(state: &mut Self::State, line: &str) -> Result<(), ParseError> {
+        state.0 += crate::parse::number::<i64>(line, line, "<n>")?;
+        state.1 += 1;
+        Ok(())
+    }
+
+    fn finish(state: Self::State) -> Result<[Answer; 2], ParseError> {
+        Ok([state.0.into(), state.1.into()])
+    }
+}
+
+#[test]
+fn test_streams_lines() {
+    let answers = solve::<Sum>("1\r\n2\n3".as_bytes()).unwrap();
+    assert_eq!(answers, [Answer::from(6), Answer::from(3)]);
+
+    let Err(StreamError::Parse(err)) = solve::<Sum>("1\n2\nx\n".as_bytes()) else {
+        panic!("expected a parse error");
+    };
+    assert_eq!(err.to_string(), "day1 line 3 column 1: expected \"<n>\"");
+}
@@ -0,0 +1,273 @@
+use common::parse::number;
+use common::stream::Streaming;
+use common::{Answer, ParseError, Solution};
+use std::cmp::Ordering;
+use top::{RankedElf, TopElves};
+
+pub mod stats;
+pub mod top;
+
+/// The calories of a food item, or of all the food an elf carries. Any signed integer fits here,
+/// sums are checked whatever its width.
+pub type Calories = i64;
+
+pub struct Day1;
+
+common::solution_functions!(Day1);
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<Elf>;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        get_elfs(text)
+    }
+
+    fn part1(elfs: &Self::Input) -> Answer {
+        process_part1(elfs).into()
+    }
+
+    fn part2(elfs: &Self::Input) -> Answer {
+        process_part2(elfs).into()
+    }
+}
+
+impl Streaming for Day1 {
+    type State = Tally;
+
+    fn feed(tally: &mut Self::State, line: &str) -> Result<(), ParseError> {
+        if line.trim().is_empty() {
+            tally.finish_elf();
+        } else {
+            let carrying = tally.carrying.get_or_insert(0);
+            add_item(carrying, line, tally.elves + 1)?;
+        }
+        Ok(())
+    }
+
+    fn finish(mut tally: Self::State) -> Result<[Answer; 2], ParseError> {
+        tally.finish_elf();
+        let best = tally.top.ranking();
+        if best.is_empty() {
+            return Err(ParseError::expected("<calories>"));
+        }
+        let best3 = best
+            .iter()
+            .map(|ranked| i128::from(ranked.calories))
+            .sum::<i128>();
+        Ok([best[0].calories.into(), best3.into()])
+    }
+}
+
+/// The calories of the elves read so far, keeping only the elves that carry the most.
+#[derive(Debug)]
+pub struct Tally {
+    /// The calories of the elf whose food is being read, if any of it has been read yet.
+    carrying: Option<Calories>,
+    /// The number of elves whose food has been read completely.
+    elves: usize,
+    top: TopElves,
+}
+
+impl Tally {
+    fn new(k: usize) -> Self {
+        Tally {
+            carrying: None,
+            elves: 0,
+            top: TopElves::new(k),
+        }
+    }
+
+    fn finish_elf(&mut self) {
+        if let Some(calories) = self.carrying.take() {
+            self.elves += 1;
+            self.top.push(RankedElf {
+                elf: self.elves,
+                calories,
+            });
+        }
+    }
+}
+
+impl Default for Tally {
+    /// Keeps the three elves the puzzle asks about.
+    fn default() -> Self {
+        Tally::new(3)
+    }
+}
+
+fn process_part1(elfs: &[Elf]) -> Calories {
+    elfs.iter().max().unwrap().total()
+}
+
+fn process_part2(elfs: &[Elf]) -> i128 {
+    // Get the top 3 elfs
+    let mut top = TopElves::new(3);
+    for (index, elf) in elfs.iter().enumerate() {
+        top.push(RankedElf {
+            elf: index + 1,
+            calories: elf.total(),
+        });
+    }
+    // Three totals that each fit in `Calories` could add up beyond it
+    top.ranking()
+        .iter()
+        .map(|ranked| i128::from(ranked.calories))
+        .sum()
+}
+
+/// The calories of every food item an elf carries.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Elf(Vec<Calories>);
+
+impl Elf {
+    pub fn items(&self) -> &[Calories] {
+        &self.0
+    }
+
+    pub fn total(&self) -> Calories {
+        // Parsing checked that the items add up without overflowing
+        self.0.iter().sum()
+    }
+}
+
+impl PartialOrd for Elf {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Elf {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.total().cmp(&other.total())
+    }
+}
+
+fn get_elfs(text: &str) -> Result<Vec<Elf>, ParseError> {
+    // Going by line rather than splitting on "\n\n" keeps CRLF inputs apart into elves too, and
+    // lets any number of blank lines separate them
+    let mut elfs = Vec::new();
+    let mut items = Vec::new();
+    let mut carrying = 0;
+    for (index, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            if !items.is_empty() {
+                elfs.push(Elf(std::mem::take(&mut items)));
+                carrying = 0;
+            }
+        } else {
+            let item = add_item(&mut carrying, line, elfs.len() + 1)
+                .map_err(|err| err.offset_line(index))?;
+            items.push(item);
+        }
+    }
+    if !items.is_empty() {
+        elfs.push(Elf(items));
+    }
+    // Like the streamed input, there has to be an elf to answer the parts about
+    if elfs.is_empty() {
+        return Err(ParseError::expected("<calories>"));
+    }
+    Ok(elfs)
+}
+
+/// Parses a food item of the given elf, counting from 1, and adds it to what the elf carries.
+fn add_item(carrying: &mut Calories, line: &str, elf: usize) -> Result<Calories, ParseError> {
+    let item: Calories = number(line, line, &format!("<calories> of elf {}", elf))?;
+    if item < 0 {
+        return Err(
+            ParseError::expected(format!("non-negative calories of elf {}", elf)).at(line, line),
+        );
+    }
+    *carrying = carrying.checked_add(item).ok_or_else(|| {
+        ParseError::expected(format!(
+            "elf {} to carry at most {} calories",
+            elf,
+            Calories::MAX
+        ))
+        .at(line, line)
+    })?;
+    Ok(item)
+}
+
+#[test]
+fn test_example() {
+    let input = common::parse_input::<Day1>(include_str!("../example.txt")).unwrap();
+    assert_eq!(Day1::part1(&input), Answer::from(24000));
+    assert_eq!(Day1::part2(&input), Answer::from(45000));
+}
+
+#[test]
+fn test_example_streaming() {
+    let text = include_str!("../example.txt");
+    let answers = common::stream::solve::<Day1>(text.as_bytes()).unwrap();
+    assert_eq!(answers, [Answer::from(24000), Answer::from(45000)]);
+}
+
+#[test]
+fn test_functions_on_text() {
+    let text = include_str!("../example.txt");
+    assert_eq!(part1(text), Ok(Answer::from(24000)));
+    assert_eq!(part2(text), Ok(Answer::from(45000)));
+    assert_eq!(parse(text).unwrap().len(), 5);
+    assert!(part1("1000\nsnacks\n").is_err());
+}
+
+#[test]
+fn test_line_endings_and_blank_lines() {
+    let text = include_str!("../example.txt");
+    let crlf = format!("\r\n\r\n{}\r\n\r\n\r\n", text.replace('\n', "\r\n"));
+    let elfs = parse(&crlf).unwrap();
+    assert_eq!(elfs.len(), 5);
+    assert_eq!(elfs[3].items(), [7000, 8000, 9000]);
+    assert_eq!(part2(&crlf), Ok(Answer::from(45000)));
+
+    let answers = common::stream::solve::<Day1>(crlf.as_bytes()).unwrap();
+    assert_eq!(answers, [Answer::from(24000), Answer::from(45000)]);
+}
+
+#[test]
+fn test_totals_beyond_32_bits() {
+    let text = "4000000000\n4000000000\n\n9000000000\n\n1";
+    assert_eq!(part1(text), Ok(Answer::from(9000000000i64)));
+    assert_eq!(part2(text), Ok(Answer::from(17000000001i64)));
+
+    let max = format!(
+        "{}\n\n{}\n\n{}",
+        Calories::MAX,
+        Calories::MAX,
+        Calories::MAX
+    );
+    assert_eq!(
+        part2(&max),
+        Ok(Answer::Integer(3 * i128::from(Calories::MAX)))
+    );
+}
+
+#[test]
+fn test_errors_name_the_elf_and_line() {
+    let err = parse("1\n\n2\n3\n\n\n4\nfour\n").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "day1 line 8 column 1: expected \"<calories> of elf 3\""
+    );
+
+    let text = format!("1\n\n{}\n1", Calories::MAX);
+    let err = parse(&text).unwrap_err();
+    assert_eq!(err.line(), Some(4));
+    assert!(err.to_string().contains("elf 2 to carry at most"));
+
+    let err = common::stream::solve::<Day1>(text.as_bytes()).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("day1 line 4 column 1: expected \"elf 2"));
+
+    assert!(parse("\n\r\n").is_err());
+
+    let err = parse("5\n-5").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "day1 line 2 column 1: expected \"non-negative calories of elf 1\""
+    );
+}
@@ -0,0 +1,188 @@
+//! Statistics on the calories the elves carry, for looking at an inventory beyond the puzzle.
+use crate::{Calories, Elf};
+use std::collections::BTreeMap;
+use std::num::NonZeroUsize;
+
+/// The percentiles a [`Report`] has, as percentages.
+pub const PERCENTILES: [u32; 7] = [1, 10, 25, 50, 75, 90, 99];
+
+/// What the calories of an inventory look like.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub elves: usize,
+    /// The number of food items of all elves together.
+    pub items: usize,
+    /// The calories of all elves together, which can be more than a single elf could carry.
+    pub total: i128,
+    pub min: Calories,
+    pub max: Calories,
+    pub mean: f64,
+    pub median: f64,
+    /// The standard deviation of the calories of all elves.
+    pub std_dev: f64,
+    /// The calories at each of the [`PERCENTILES`], interpolated between the elves around it.
+    pub percentiles: Vec<(u32, f64)>,
+    /// How many elves carry each number of items.
+    pub item_counts: BTreeMap<usize, usize>,
+    /// The elves whose calories are further from the mean than the z-score threshold, in input
+    /// order.
+    pub outliers: Vec<Outlier>,
+    pub histogram: Vec<Bucket>,
+}
+
+/// An elf that carries far more or far fewer calories than the others.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Outlier {
+    /// Where the elf is in the input, counting from 1.
+    pub elf: usize,
+    pub calories: Calories,
+    /// How many standard deviations the calories are away from the mean.
+    pub z_score: f64,
+}
+
+/// The number of elves carrying calories from `start` up to and including `end`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bucket {
+    pub start: Calories,
+    pub end: Calories,
+    pub elves: usize,
+}
+
+/// Works out the statistics of the elves, with the outliers beyond `z_threshold` standard
+/// deviations and a histogram of at most `bins` buckets. Returns `None` when there are no elves.
+pub fn report(elfs: &[Elf], z_threshold: f64, bins: NonZeroUsize) -> Option<Report> {
+    let calories = elfs.iter().map(Elf::total).collect::<Vec<_>>();
+    let mut sorted = calories.clone();
+    sorted.sort_unstable();
+    let (&min, &max) = (sorted.first()?, sorted.last()?);
+
+    let total = calories.iter().map(|&c| i128::from(c)).sum::<i128>();
+    let mean = total as f64 / calories.len() as f64;
+    let variance = calories
+        .iter()
+        .map(|&c| (c as f64 - mean).powi(2))
+        .sum::<f64>()
+        / calories.len() as f64;
+    let std_dev = variance.sqrt();
+
+    let mut item_counts = BTreeMap::new();
+    for elf in elfs {
+        *item_counts.entry(elf.items().len()).or_insert(0) += 1;
+    }
+
+    // When all elves carry the same, none of them stands out
+    let outliers = if std_dev > 0.0 {
+        (1..)
+            .zip(&calories)
+            .map(|(elf, &calories)| Outlier {
+                elf,
+                calories,
+                z_score: (calories as f64 - mean) / std_dev,
+            })
+            .filter(|outlier| outlier.z_score.abs() > z_threshold)
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    Some(Report {
+        elves: calories.len(),
+        items: elfs.iter().map(|elf| elf.items().len()).sum(),
+        total,
+        min,
+        max,
+        mean,
+        median: percentile(&sorted, 50),
+        std_dev,
+        percentiles: PERCENTILES
+            .iter()
+            .map(|&p| (p, percentile(&sorted, p)))
+            .collect(),
+        item_counts,
+        outliers,
+        histogram: histogram(&sorted, bins),
+    })
+}
+
+/// The value below which `percentage` percent of the sorted values fall, interpolating linearly
+/// between the two values closest to it.
+fn percentile(sorted: &[Calories], percentage: u32) -> f64 {
+    let rank = (sorted.len() - 1) as f64 * percentage as f64 / 100.0;
+    let (below, above) = (rank.floor() as usize, rank.ceil() as usize);
+    let fraction = rank - below as f64;
+    let (below, above) = (sorted[below] as f64, sorted[above] as f64);
+    below + (above - below) * fraction
+}
+
+/// Splits the range of the sorted values into `bins` buckets of equal width, or fewer when the
+/// range is too narrow for that many buckets of whole calories.
+fn histogram(sorted: &[Calories], bins: NonZeroUsize) -> Vec<Bucket> {
+    // Worked out in i128, as the range from the fewest to the most calories need not fit in
+    // `Calories`
+    let (min, max) = (i128::from(sorted[0]), i128::from(sorted[sorted.len() - 1]));
+    let bins = bins.get() as i128;
+    let width = (max - min + 1 + bins - 1) / bins;
+    let bound = |value: i128| Calories::try_from(value).unwrap();
+    let mut buckets = (0..)
+        .map(|i| min + i * width)
+        .take_while(|&start| start <= max)
+        .map(|start| Bucket {
+            start: bound(start),
+            end: bound((start + width - 1).min(max)),
+            elves: 0,
+        })
+        .collect::<Vec<_>>();
+    for &value in sorted {
+        buckets[((i128::from(value) - min) / width) as usize].elves += 1;
+    }
+    buckets
+}
+
+#[test]
+fn test_report() {
+    let elfs = crate::parse(include_str!("../example.txt")).unwrap();
+    let summary = report(&elfs, 1.5, NonZeroUsize::new(4).unwrap()).unwrap();
+
+    assert_eq!(summary.elves, 5);
+    assert_eq!(summary.items, 10);
+    assert_eq!(summary.total, 55000);
+    assert_eq!((summary.min, summary.max), (4000, 24000));
+    assert_eq!(summary.mean, 11000.0);
+    assert_eq!(summary.median, 10000.0);
+    assert_eq!(summary.percentiles[2], (25, 6000.0));
+    assert!((summary.std_dev - 6985.700).abs() < 0.001);
+    assert_eq!(
+        summary.item_counts,
+        BTreeMap::from([(1, 2), (2, 1), (3, 2)])
+    );
+
+    assert_eq!(summary.outliers.len(), 1);
+    assert_eq!(summary.outliers[0].elf, 4);
+    assert_eq!(
+        summary.histogram,
+        [
+            Bucket {
+                start: 4000,
+                end: 9000,
+                elves: 2
+            },
+            Bucket {
+                start: 9001,
+                end: 14001,
+                elves: 2
+            },
+            Bucket {
+                start: 14002,
+                end: 19002,
+                elves: 0
+            },
+            Bucket {
+                start: 19003,
+                end: 24000,
+                elves: 1
+            }
+        ]
+    );
+
+    assert_eq!(report(&[], 3.0, NonZeroUsize::MIN), None);
+}
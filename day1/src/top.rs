@@ -0,0 +1,101 @@
+//! The elves carrying the most calories, found in a single pass without keeping the others.
+use crate::{Calories, Day1, Tally};
+use common::stream::{self, StreamError};
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::io::BufRead;
+
+/// An elf with the calories it carries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RankedElf {
+    /// Where the elf is in the input, counting from 1.
+    pub elf: usize,
+    pub calories: Calories,
+}
+
+/// Orders elves from worst to best: fewer calories first, and of elves with the same calories the
+/// one that comes later in the input, so the first of them keeps its place.
+impl Ord for RankedElf {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.calories, Reverse(self.elf)).cmp(&(other.calories, Reverse(other.elf)))
+    }
+}
+
+impl PartialOrd for RankedElf {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The `k` elves carrying the most calories of the elves seen so far.
+///
+/// They are kept in a min-heap, so every elf is checked against the worst of them in constant
+/// time and only the `k` best are ever kept, however many elves there are.
+#[derive(Debug, Clone)]
+pub struct TopElves {
+    k: usize,
+    heap: BinaryHeap<Reverse<RankedElf>>,
+}
+
+impl TopElves {
+    pub fn new(k: usize) -> Self {
+        TopElves {
+            k,
+            heap: BinaryHeap::new(),
+        }
+    }
+
+    pub fn push(&mut self, elf: RankedElf) {
+        if self.heap.len() < self.k {
+            self.heap.push(Reverse(elf));
+        } else if let Some(mut worst) = self.heap.peek_mut() {
+            if elf > worst.0 {
+                *worst = Reverse(elf);
+            }
+        }
+    }
+
+    /// The elves kept, from most to least calories.
+    pub fn ranking(&self) -> Vec<RankedElf> {
+        let mut ranking = self
+            .heap
+            .iter()
+            .map(|Reverse(elf)| *elf)
+            .collect::<Vec<_>>();
+        ranking.sort_by(|a, b| b.cmp(a));
+        ranking
+    }
+}
+
+/// Reads the elves from the input a line at a time and ranks the `k` of them carrying the most
+/// calories, from most to least.
+pub fn top_elves(reader: impl BufRead, k: usize) -> Result<Vec<RankedElf>, StreamError> {
+    let mut tally = Tally::new(k);
+    stream::feed::<Day1>(reader, &mut tally)?;
+    tally.finish_elf();
+    Ok(tally.top.ranking())
+}
+
+#[test]
+fn test_top_elves() {
+    let text = include_str!("../example.txt");
+    let ranking = top_elves(text.as_bytes(), 2).unwrap();
+    assert_eq!(
+        ranking,
+        [
+            RankedElf {
+                elf: 4,
+                calories: 24000
+            },
+            RankedElf {
+                elf: 3,
+                calories: 11000
+            }
+        ]
+    );
+
+    // Ties keep the elf that came first, and a k beyond the number of elves ranks all of them
+    let ranking = top_elves("5\n\n7\n\n5\n\n1".as_bytes(), 10).unwrap();
+    let elves = ranking.iter().map(|ranked| ranked.elf).collect::<Vec<_>>();
+    assert_eq!(elves, [2, 1, 3, 4]);
+}
@@ -0,0 +1,250 @@
+use common::parse::{lines, number};
+use common::stream::Streaming;
+use common::{frames, Answer, ParseError, Solution};
+use std::str::FromStr;
+
+pub struct Day10;
+
+common::solution_functions!(Day10);
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Instruction>;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        parse_input(text)
+    }
+
+    fn part1(instructions: &Self::Input) -> Answer {
+        process_part1(instructions).into()
+    }
+
+    fn part2(instructions: &Self::Input) -> Answer {
+        process_part2(instructions).into()
+    }
+}
+
+// --- Part 1 ---
+// You avoid the ropes, plunge into the river, and swim to shore.
+// The Elves yell something about meeting back up with them upriver, but the river is too loud to tell exactly what they're saying. They finish crossing the bridge and disappear from view.
+// Situations like this must be why the Elves prioritized getting the communication system on your handheld device working. You pull it out of your pack, but the amount of water slowly draining from a big crack in its screen tells you it probably won't be of much immediate use.
+// Unless, that is, you can design a replacement for the device's video system! It seems to be some kind of cathode-ray tube screen and simple CPU that are both driven by a precise clock circuit. The clock circuit ticks at a constant rate; each tick is called a cycle.
+// Start by figuring out the signal being sent by the CPU. The CPU has a single register, X, which starts with the value 1. It supports only two instructions:
+//
+// addx V takes two cycles to complete. After two cycles, the X register is increased by the value V. (V can be negative.)
+// noop takes one cycle to complete. It has no other effect.
+// The CPU uses these instructions in a program (your puzzle input) to, somehow, tell the screen what to draw.
+//
+// Consider the following small program:
+//
+// noop
+// addx 3
+// addx -5
+// Execution of this program proceeds as follows:
+//
+// At the start of the first cycle, the noop instruction begins execution. During the first cycle, X is 1. After the first cycle, the noop instruction finishes execution, doing nothing.
+// At the start of the second cycle, the addx 3 instruction begins execution. During the second cycle, X is still 1.
+// During the third cycle, X is still 1. After the third cycle, the addx 3 instruction finishes execution, setting X to 4.
+// At the start of the fourth cycle, the addx -5 instruction begins execution. During the fourth cycle, X is still 4.
+// During the fifth cycle, X is still 4. After the fifth cycle, the addx -5 instruction finishes execution, setting X to -1.
+// Maybe you can learn something by looking at the value of the X register throughout execution. For now, consider the signal strength (the cycle number multiplied by the value of the X register) during the 20th cycle and every 40 cycles after that (that is, during the 20th, 60th, 100th, 140th, 180th, and 220th cycles).
+//
+// Find the signal strength during the 20th, 60th, 100th, 140th, 180th, and 220th cycles. What is the sum of these six signal strengths?
+
+impl Streaming for Day10 {
+    type State = Device;
+
+    fn feed(device: &mut Self::State, line: &str) -> Result<(), ParseError> {
+        device.execute(&line.parse()?, |_| {});
+        Ok(())
+    }
+
+    fn finish(device: Self::State) -> Result<[Answer; 2], ParseError> {
+        Ok([device.signal_strength.into(), device.rows().into()])
+    }
+}
+
+pub enum Instruction {
+    AddX(i32),
+    Noop,
+}
+
+impl Instruction {
+    fn cycle(&self, x: &mut i32) {
+        match self {
+            Instruction::AddX(v) => *x += v,
+            Instruction::Noop => (),
+        }
+    }
+
+    fn cycle_duration(&self) -> usize {
+        match self {
+            Instruction::AddX(_) => 2,
+            Instruction::Noop => 1,
+        }
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(' ') {
+            Some(("addx", value)) => Ok(Instruction::AddX(number(s, value, "<value>")?)),
+            None if s == "noop" => Ok(Instruction::Noop),
+            _ => Err(ParseError::expected("addx <value> or noop")),
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    lines(input)
+}
+
+fn add_special_cycles(cycle: i64, x: i32, signal_strength: &mut i64) {
+    if (cycle - 20) % 40 == 0 {
+        let strength = cycle * i64::from(x);
+        tracing::debug!(cycle, x, signal_strength = strength, "special cycle");
+        *signal_strength += strength;
+    }
+}
+
+/// The CPU with the CRT it drives, running one instruction at a time.
+pub struct Device {
+    x: i32,
+    /// The number of cycles that have passed.
+    cycle: i64,
+    signal_strength: i64,
+    screen: Vec<Vec<char>>,
+}
+
+impl Default for Device {
+    fn default() -> Self {
+        Device {
+            x: 1,
+            cycle: 0,
+            signal_strength: 0,
+            screen: vec![vec![' '; 40]; 6],
+        }
+    }
+}
+
+impl Device {
+    /// Runs the instruction, calling `drawn` every time a pixel has been drawn.
+    fn execute(&mut self, instruction: &Instruction, mut drawn: impl FnMut(&Device)) {
+        for _ in 0..instruction.cycle_duration() {
+            let position = (self.cycle % 40) as i32;
+            // If the position is one more or less than the X register, then the sprite is visible.
+            // Cycles after the last pixel of the screen don't draw anything.
+            let row = self.screen.get_mut(self.cycle as usize / 40);
+            if let Some(pixel) = row.and_then(|row| row.get_mut(position as usize)) {
+                *pixel = if ((self.x - 1)..=(self.x + 1)).contains(&position) {
+                    '#'
+                } else {
+                    '.'
+                };
+                drawn(self);
+            }
+
+            self.cycle += 1;
+            add_special_cycles(self.cycle, self.x, &mut self.signal_strength);
+        }
+        instruction.cycle(&mut self.x);
+    }
+
+    fn rows(&self) -> Vec<String> {
+        self.screen.iter().map(|row| row.iter().collect()).collect()
+    }
+}
+
+fn process_part1(instructions: &[Instruction]) -> i64 {
+    let mut device = Device::default();
+    for instruction in instructions {
+        device.execute(instruction, |_| {});
+    }
+    device.signal_strength
+}
+
+// --- Part Two ---
+//
+// It seems like the X register controls the horizontal position of a sprite. Specifically, the sprite is 3 pixels wide, and the X register sets the horizontal position of the middle of that sprite. (In this system, there is no such thing as "vertical position": if the sprite's horizontal position puts its pixels where the CRT is currently drawing, then those pixels will be drawn.)
+//
+// You count the pixels on the CRT: 40 wide and 6 high. This CRT screen draws the top row of pixels left-to-right, then the row below that, and so on. The left-most pixel in each row is in position 0, and the right-most pixel in each row is in position 39.
+//
+// Like the CPU, the CRT is tied closely to the clock circuit: the CRT draws a single pixel during each cycle. Representing each pixel of the screen as a #, here are the cycles during which the first and last pixel in each row are drawn:
+//
+// Cycle   1 -> ######################################## <- Cycle  40
+// Cycle  41 -> ######################################## <- Cycle  80
+// Cycle  81 -> ######################################## <- Cycle 120
+// Cycle 121 -> ######################################## <- Cycle 160
+// Cycle 161 -> ######################################## <- Cycle 200
+// Cycle 201 -> ######################################## <- Cycle 240
+// So, by carefully timing the CPU instructions and the CRT drawing operations, you should be able to determine whether the sprite is visible the instant each pixel is drawn. If the sprite is positioned such that one of its three pixels is the pixel currently being drawn, the screen produces a lit pixel (#); otherwise, the screen leaves the pixel dark (.).
+//
+// Render the image given by your program. What eight capital letters appear on your CRT?
+
+fn process_part2(instructions: &[Instruction]) -> Vec<String> {
+    let mut device = Device::default();
+    for instruction in instructions {
+        device.execute(instruction, |device| {
+            frames::record(|| screen_frame(device.x, device.cycle, &device.screen))
+        });
+    }
+    device.rows()
+}
+
+/// Draws the screen with the sprite in red on the row that is being drawn, and an X where the
+/// sprite is over a pixel that is not drawn yet.
+fn screen_frame(x: i32, cycle: i64, screen: &[Vec<char>]) -> String {
+    let mut frame = format!("||||||||||||||||| {} [{}] ||||||||||||||||||||\n", cycle, x);
+    for (row_index, row) in screen.iter().enumerate() {
+        for (column_index, c) in row.iter().enumerate() {
+            if ((x - 1)..=(x + 1)).contains(&(column_index as i32))
+                && cycle as usize / 40 == row_index
+            {
+                let c = if c.is_whitespace() { 'X' } else { *c };
+                frame.push_str(&format!("\x1b[31m{}\x1b[0m", c));
+            } else {
+                frame.push(*c);
+            }
+        }
+        frame.push('\n');
+    }
+    frame
+}
+
+#[test]
+fn test_example() {
+    let input = common::parse_input::<Day10>(include_str!("../example.txt")).unwrap();
+    assert_eq!(Day10::part1(&input), Answer::from(13140));
+
+    let screen = [
+        "##..##..##..##..##..##..##..##..##..##..",
+        "###...###...###...###...###...###...###.",
+        "####....####....####....####....####....",
+        "#####.....#####.....#####.....#####.....",
+        "######......######......######......####",
+        "#######.......#######.......#######.....",
+    ];
+    assert_eq!(
+        Day10::part2(&input),
+        Answer::from(screen.map(String::from).to_vec())
+    );
+}
+
+#[test]
+fn test_example_streaming() {
+    let text = include_str!("../example.txt");
+    let input = common::parse_input::<Day10>(text).unwrap();
+    let answers = common::stream::solve::<Day10>(text.as_bytes()).unwrap();
+    assert_eq!(answers, [Day10::part1(&input), Day10::part2(&input)]);
+}
+
+#[test]
+fn test_signal_strength_past_i32() {
+    // Every 40 cycles from the 20th of 500000 adds up to more than fits in an i32
+    let text = "noop\n".repeat(500000);
+    let answers = common::stream::solve::<Day10>(text.as_bytes()).unwrap();
+    assert_eq!(answers[0], Answer::from(3125000000i64));
+}
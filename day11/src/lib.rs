@@ -0,0 +1,393 @@
+use crate::Context::{StressFree, Stressed};
+use common::parse::{blocks_with, number};
+use common::{Answer, ParseError, Solution};
+use regex::Regex;
+use std::collections::VecDeque;
+use std::str::FromStr;
+
+pub struct Day11;
+
+common::solution_functions!(Day11);
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<Monkey>;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        parse_input(text)
+    }
+
+    fn part1(monkeys: &Self::Input) -> Answer {
+        process_part1(monkeys).into()
+    }
+
+    fn part2(monkeys: &Self::Input) -> Answer {
+        process_part2(monkeys).into()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    _id: usize,
+    items: VecDeque<u64>,
+    operation: Operation,
+    test: u64,     // Which number an item should be divisible by
+    if_true: u64,  // The next monkey to throw to if the item is divisible by test
+    if_false: u64, // The next monkey to throw to if the item is not divisible by test
+
+    inspect_count: u64,
+}
+
+impl FromStr for Monkey {
+    type Err = ParseError;
+
+    /// Parse the following format:
+    /// Monkey 0:
+    ///   Starting items: 79, 98
+    ///   Operation: new = old * 19
+    ///   Test: divisible by 23
+    ///     If true: throw to monkey 2
+    ///     If false: throw to monkey 3
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines = s.lines().collect::<Vec<_>>();
+
+        // Parse the monkey id
+        let id_regex = Regex::new(r"Monkey (\d+):").unwrap();
+        let id = parse_line(&lines, 0, id_regex, "Monkey <id>:", |line, id| {
+            number(line, id, "<id>")
+        })?;
+
+        // Parse the starting items
+        let items_regex = Regex::new(r"Starting items: (.*)").unwrap();
+        let items = parse_line(
+            &lines,
+            1,
+            items_regex,
+            "Starting items: <items>",
+            |line, items| {
+                items
+                    .split(", ")
+                    .map(|item| number(line, item, "<item>"))
+                    .collect::<Result<VecDeque<u64>, _>>()
+            },
+        )?;
+
+        // Parse the operation
+        let operation_regex = Regex::new(r"Operation: new = old (.*)").unwrap();
+        let operation = parse_line(
+            &lines,
+            2,
+            operation_regex,
+            "Operation: new = old <operator> <operand>",
+            |line, operation| {
+                operation
+                    .parse()
+                    .map_err(|err: ParseError| err.at(line, operation))
+            },
+        )?;
+
+        // Parse the test
+        let test_regex = Regex::new(r"Test: divisible by (\d+)").unwrap();
+        let test = parse_line(
+            &lines,
+            3,
+            test_regex,
+            "Test: divisible by <n>",
+            |line, test| match number(line, test, "<n>")? {
+                0 => Err(ParseError::expected("<n> greater than 0").at(line, test)),
+                test => Ok(test),
+            },
+        )?;
+
+        // Parse the if_true
+        let if_true_regex = Regex::new(r"If true: throw to monkey (\d+)").unwrap();
+        let if_true = parse_line(
+            &lines,
+            4,
+            if_true_regex,
+            "If true: throw to monkey <id>",
+            |line, id| number(line, id, "<id>"),
+        )?;
+
+        // Parse the if_false
+        let if_false_regex = Regex::new(r"If false: throw to monkey (\d+)").unwrap();
+        let if_false = parse_line(
+            &lines,
+            5,
+            if_false_regex,
+            "If false: throw to monkey <id>",
+            |line, id| number(line, id, "<id>"),
+        )?;
+
+        Ok(Monkey {
+            _id: id,
+            items,
+            operation,
+            test,
+            if_true,
+            if_false,
+            inspect_count: 0,
+        })
+    }
+}
+
+/// Parses every monkey, making sure there are at least two to pass items between and that they
+/// only throw to monkeys that are there.
+fn parse_input(text: &str) -> Result<Vec<Monkey>, ParseError> {
+    let count = text.split("\n\n").count();
+    let monkeys = blocks_with(text, |block| {
+        let monkey = block.parse::<Monkey>()?;
+        let lines = block.lines().collect::<Vec<_>>();
+        for (index, target) in [(4, monkey.if_true), (5, monkey.if_false)] {
+            if target as usize >= count {
+                let line = lines[index];
+                let id = line.rsplit(' ').next().unwrap();
+                return Err(ParseError::expected(format!("<id 0-{}>", count - 1))
+                    .at(line, id)
+                    .offset_line(index));
+            }
+        }
+        Ok(monkey)
+    })?;
+    if monkeys.len() < 2 {
+        return Err(ParseError::expected("another monkey").offset_line(text.lines().count()));
+    }
+    Ok(monkeys)
+}
+
+/// Matches the line with the given index against the regex and parses the value it captured.
+fn parse_line<T>(
+    lines: &[&str],
+    index: usize,
+    regex: Regex,
+    expected: &str,
+    parse: impl Fn(&str, &str) -> Result<T, ParseError>,
+) -> Result<T, ParseError> {
+    let line = lines.get(index).copied().unwrap_or_default();
+    regex
+        .captures(line)
+        .and_then(|captures| captures.get(1))
+        .ok_or_else(|| ParseError::expected(expected))
+        .and_then(|value| parse(line, value.as_str()))
+        .map_err(|err| err.offset_line(index))
+}
+
+#[derive(Debug, Clone)]
+struct Operation {
+    operator: Operator,
+    operand: Operand,
+}
+
+impl Operation {
+    fn apply(&self, old: u64) -> u64 {
+        let second = self.operand.apply(old);
+        self.operator.apply(old, second)
+    }
+}
+
+impl FromStr for Operation {
+    type Err = ParseError;
+
+    /// Parse the following format:
+    /// new = old * 19
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (operator, operand) = s
+            .split_once(' ')
+            .ok_or_else(|| ParseError::expected("<operator> <operand>"))?;
+        let operator = operator
+            .parse::<Operator>()
+            .map_err(|err| err.at(s, operator))?;
+        let operand = match operand.parse::<Operand>() {
+            Ok(Operand::Constant(0)) if matches!(operator, Operator::Divide) => {
+                Err(ParseError::expected("old or <n> other than 0"))
+            }
+            parsed => parsed,
+        }
+        .map_err(|err| err.at(s, operand))?;
+        Ok(Operation { operator, operand })
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Operator {
+    Add,
+    Multiply,
+    Divide,
+    Subtract,
+}
+
+impl Operator {
+    fn apply(&self, a: u64, b: u64) -> u64 {
+        match self {
+            Operator::Add => a + b,
+            Operator::Multiply => a * b,
+            Operator::Divide => a / b,
+            Operator::Subtract => a - b,
+        }
+    }
+}
+
+impl FromStr for Operator {
+    type Err = ParseError;
+
+    /// Parse the following format:
+    /// new = old * 19
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "+" => Ok(Operator::Add),
+            "*" => Ok(Operator::Multiply),
+            "/" => Ok(Operator::Divide),
+            "-" => Ok(Operator::Subtract),
+            _ => Err(ParseError::expected("+, *, / or -")),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Operand {
+    Constant(u64),
+    Item,
+}
+
+impl Operand {
+    fn apply(&self, item: u64) -> u64 {
+        match self {
+            Operand::Constant(c) => *c,
+            Operand::Item => item,
+        }
+    }
+}
+
+impl FromStr for Operand {
+    type Err = ParseError;
+
+    /// Parse the following format:
+    /// new = old * 19
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "old" => Ok(Operand::Item),
+            _ => Ok(Operand::Constant(number(s, s, "old or <n>")?)),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum Context {
+    StressFree,
+    Stressed { super_modulo: u64 },
+}
+
+struct Inspection {
+    monkey: usize,
+    item: u64,
+}
+
+fn simplify_item(context: &Context, item: u64) -> u64 {
+    match context {
+        StressFree => item / 3,
+        Stressed {
+            super_modulo: total,
+        } => item % total,
+    }
+}
+
+/// Inspect the first item in the monkey's inventory and return the result
+/// of the inspection.
+fn inspect_item(context: &Context, monkey: &mut Monkey) -> Option<Inspection> {
+    let item = monkey.items.pop_front()?;
+
+    monkey.inspect_count += 1;
+
+    let new_item = monkey.operation.apply(item);
+    let new_item = simplify_item(context, new_item);
+    let next_monkey = if new_item.is_multiple_of(monkey.test) {
+        monkey.if_true
+    } else {
+        monkey.if_false
+    };
+
+    Some(Inspection {
+        monkey: next_monkey as usize,
+        item: new_item,
+    })
+}
+
+fn simulate_monkey(context: &Context, monkey: &mut Monkey) -> Vec<Inspection> {
+    let mut inspections = Vec::new();
+    while let Some(inspection) = inspect_item(context, monkey) {
+        inspections.push(inspection);
+    }
+    inspections
+}
+
+fn simulate_round(context: &Context, monkeys: &mut [Monkey]) {
+    for i in 0..monkeys.len() {
+        let inspections = simulate_monkey(context, &mut monkeys[i]);
+        for inspection in inspections {
+            monkeys[inspection.monkey].items.push_back(inspection.item);
+        }
+    }
+}
+
+fn calculate_monkey_business(monkeys: &[Monkey]) -> u64 {
+    let mut counts: Vec<u64> = monkeys.iter().map(|m| m.inspect_count).collect();
+    counts.sort();
+    counts.reverse();
+    counts[0] * counts[1]
+}
+
+fn process_part1(monkeys: &[Monkey]) -> u64 {
+    let mut monkeys = monkeys.to_vec();
+
+    for _ in 0..20 {
+        simulate_round(&StressFree, &mut monkeys);
+    }
+
+    calculate_monkey_business(&monkeys)
+}
+
+fn process_part2(monkeys: &[Monkey]) -> u64 {
+    let mut monkeys = monkeys.to_vec();
+
+    let context = Stressed {
+        super_modulo: monkeys.iter().map(|m| m.test).product(),
+    };
+
+    for _ in 0..10000 {
+        simulate_round(&context, &mut monkeys);
+    }
+
+    calculate_monkey_business(&monkeys)
+}
+
+#[test]
+fn test_example() {
+    let input = common::parse_input::<Day11>(include_str!("../example.txt")).unwrap();
+    assert_eq!(Day11::part1(&input), Answer::from(10605));
+    assert_eq!(Day11::part2(&input), Answer::from(2713310158_u64));
+}
+
+#[test]
+fn test_invalid_monkeys() {
+    let error = |text: &str| part1(text).unwrap_err().to_string();
+    let example = include_str!("../example.txt");
+    // A monkey alone that throws to itself has no one to play with
+    let alone = example.split("\n\n").next().unwrap();
+    let alone = alone
+        .replace("monkey 2", "monkey 0")
+        .replace("monkey 3", "monkey 0");
+    assert_eq!(error(&alone), "day11 line 7: expected \"another monkey\"");
+    assert_eq!(
+        error(&example.replace("divisible by 23", "divisible by 0")),
+        "day11 line 4 column 22: expected \"<n> greater than 0\""
+    );
+    assert_eq!(
+        error(&example.replace("new = old * 19", "new = old / 0")),
+        "day11 line 3 column 26: expected \"old or <n> other than 0\""
+    );
+    assert_eq!(
+        error(&example.replace("If true: throw to monkey 2", "If true: throw to monkey 4")),
+        "day11 line 5 column 30: expected \"<id 0-3>\""
+    );
+}
@@ -0,0 +1,61 @@
+//! Random heightmaps, to check the solutions against each other on more than the example.
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// A small heightmap in the format of the puzzle input, with the start and the end at different
+/// squares. The elevation changes little between neighbouring squares, like on a hill, and a
+/// winding path climbs from the start to the end one step at a time, as the end is at elevation z
+/// and the parts need a way to it. The same seed always gives the same heightmap.
+pub fn input(seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    // The path needs 25 steps to climb from a to z
+    let width = rng.gen_range(3..=12);
+    let height = rng.gen_range(27usize.div_ceil(width)..=12);
+
+    let mut elevations = vec![vec![0u8; width]; height];
+    for y in 0..height {
+        for x in 0..width {
+            // Follow the squares above and to the left, which are already known
+            let previous = match (x, y) {
+                (0, 0) => 0,
+                (0, _) => elevations[y - 1][x],
+                (_, 0) => elevations[y][x - 1],
+                _ => elevations[y - 1][x].max(elevations[y][x - 1]),
+            };
+            elevations[y][x] = (previous as i32 + rng.gen_range(-2..=2)).clamp(0, 25) as u8;
+        }
+    }
+
+    // The path winds through the rows, left to right and back, from the start to the end
+    let squares = width * height;
+    let start = rng.gen_range(0..=squares - 26);
+    let end = rng.gen_range(start + 25..squares);
+    let square = |index: usize| {
+        let y = index / width;
+        let x = if y.is_multiple_of(2) {
+            index % width
+        } else {
+            width - 1 - index % width
+        };
+        (x, y)
+    };
+    for index in start..=end {
+        let (x, y) = square(index);
+        elevations[y][x] = 25usize.saturating_sub(end - index) as u8;
+    }
+
+    let mut rows: Vec<Vec<char>> = elevations
+        .iter()
+        .map(|row| row.iter().map(|e| (b'a' + e) as char).collect())
+        .collect();
+
+    let (x, y) = square(start);
+    rows[y][x] = 'S';
+    let (x, y) = square(end);
+    rows[y][x] = 'E';
+
+    rows.into_iter()
+        .map(|row| row.into_iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,184 @@
+use common::search::{astar, bfs};
+use common::{Answer, Grid, ParseError, Point, Solution};
+
+pub mod generate;
+
+pub struct Day12;
+
+common::solution_functions!(Day12);
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Grid<Node>;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        let grid = parse_input(text)?;
+
+        // Both parts walk to the end, and the first part starts at the start
+        if find_start(&grid).is_none() {
+            return Err(ParseError::expected("S somewhere in the heightmap"));
+        }
+        let Some(end) = find_end(&grid) else {
+            return Err(ParseError::expected("E somewhere in the heightmap"));
+        };
+        // The start is as low as it gets, so the second part gets to the end if the first does
+        if fewest_steps(&grid).is_none() {
+            return Err(ParseError::expected("E that can be climbed to from S")
+                .at_column(end.x as usize + 1)
+                .offset_line(end.y as usize));
+        }
+        Ok(grid)
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        process_part1(grid).into()
+    }
+
+    fn part2(grid: &Self::Input) -> Answer {
+        process_part2(grid).into()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Node {
+    Start,
+    End,
+    Step { elevation: u8 },
+}
+
+impl Node {
+    fn elevation(&self) -> u8 {
+        match self {
+            Node::Start => 0,
+            Node::End => 25,
+            Node::Step { elevation } => *elevation,
+        }
+    }
+}
+
+fn parse_node(c: char) -> Option<Node> {
+    match c {
+        'S' => Some(Node::Start),
+        'E' => Some(Node::End),
+        'a'..='z' => Some(Node::Step {
+            elevation: c as u8 - b'a',
+        }),
+        _ => None,
+    }
+}
+
+fn parse_input(text: &str) -> Result<Grid<Node>, ParseError> {
+    Grid::parse(text, "<elevation a-z>, S or E", parse_node)
+}
+
+fn find_start(nodes: &Grid<Node>) -> Option<Point> {
+    nodes.position(|node| matches!(node, Node::Start))
+}
+
+fn find_end(nodes: &Grid<Node>) -> Option<Point> {
+    nodes.position(|node| matches!(node, Node::End))
+}
+
+fn get_neighbors(nodes: &Grid<Node>, point: Point) -> Vec<Point> {
+    let elevation = nodes[point].elevation();
+    nodes
+        .neighbours(point)
+        .filter(|neighbor| nodes[*neighbor].elevation() <= elevation + 1)
+        .collect()
+}
+
+#[test]
+fn test_get_neighbors() {
+    let input = "aby\nacx";
+    let nodes = parse_input(input).unwrap();
+    let neighbors = get_neighbors(&nodes, Point::new(1, 0));
+    assert_eq!(neighbors.len(), 2);
+}
+
+fn process_part1(grid: &Grid<Node>) -> u32 {
+    fewest_steps(grid).unwrap()
+}
+
+fn fewest_steps(grid: &Grid<Node>) -> Option<u32> {
+    let start = find_start(grid).unwrap();
+    let end = find_end(grid).unwrap();
+
+    // Every step costs the same, and the distance to the end never overestimates the steps left
+    let search = astar(
+        [start],
+        |point| {
+            get_neighbors(grid, *point)
+                .into_iter()
+                .map(|neighbor| (neighbor, 1))
+        },
+        |point| point.manhattan_distance(end),
+        |point| *point == end,
+    );
+    search.cost()
+}
+
+fn process_part2(grid: &Grid<Node>) -> u32 {
+    fewest_steps_from_lowest(grid).unwrap()
+}
+
+fn lowest_points(grid: &Grid<Node>) -> impl Iterator<Item = Point> + '_ {
+    grid.iter()
+        .filter(|(_, node)| node.elevation() == 0)
+        .map(|(point, _)| point)
+}
+
+fn fewest_steps_from_lowest(grid: &Grid<Node>) -> Option<u32> {
+    // Searching from every square of elevation a at once finds the closest one
+    let search = bfs(
+        lowest_points(grid),
+        |point| get_neighbors(grid, *point),
+        |point| matches!(grid[*point], Node::End),
+    );
+    search.cost()
+}
+
+/// The fewest steps from a single start, as a reference for the searches above.
+#[cfg(test)]
+fn fewest_steps_by_bfs(grid: &Grid<Node>, start: Point) -> Option<u32> {
+    let search = bfs(
+        [start],
+        |point| get_neighbors(grid, *point),
+        |point| matches!(grid[*point], Node::End),
+    );
+    search.cost()
+}
+
+#[test]
+fn test_example() {
+    let input = common::parse_input::<Day12>(include_str!("../example.txt")).unwrap();
+    assert_eq!(Day12::part1(&input), Answer::from(31));
+    assert_eq!(Day12::part2(&input), Answer::from(29));
+}
+
+#[test]
+fn test_unreachable_end() {
+    let text = include_str!("../example.txt").replace("xxl", "zzl");
+    assert_eq!(
+        part1(&text).unwrap_err().to_string(),
+        "day12 line 3 column 6: expected \"E that can be climbed to from S\""
+    );
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #![proptest_config(proptest::test_runner::Config::with_cases(128))]
+
+    #[test]
+    fn test_searches_match_a_search_per_start(seed in proptest::prelude::any::<u64>()) {
+        let grid = common::parse_input::<Day12>(&generate::input(seed)).unwrap();
+
+        let start = find_start(&grid).unwrap();
+        proptest::prop_assert_eq!(fewest_steps(&grid), fewest_steps_by_bfs(&grid, start));
+
+        let reference = lowest_points(&grid)
+            .filter_map(|start| fewest_steps_by_bfs(&grid, start))
+            .min();
+        proptest::prop_assert_eq!(fewest_steps_from_lowest(&grid), reference);
+    }
+}
@@ -0,0 +1,311 @@
+use common::parse::{blocks_with, number};
+use common::{Answer, ParseError, Solution};
+use std::cmp::Ordering;
+use std::cmp::Ordering::{Equal, Greater, Less};
+use std::fmt::{Display, Formatter};
+use std::ops::Not;
+use std::str::FromStr;
+use OrderingResult::{Correct, Inconclusive, Incorrect};
+
+pub struct Day13;
+
+common::solution_functions!(Day13);
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<(Data, Data)>;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        parse_input(text)
+    }
+
+    fn part1(pairs: &Self::Input) -> Answer {
+        process_part1(pairs).into()
+    }
+
+    fn part2(pairs: &Self::Input) -> Answer {
+        process_part2(pairs).into()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Data {
+    List(Vec<Data>),
+    Number(i32),
+}
+
+impl Display for Data {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Data::List(list) => {
+                let inner = list
+                    .iter()
+                    .map(|data| data.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                write!(f, "[{}]", inner)
+            }
+            Data::Number(number) => write!(f, "{}", number),
+        }
+    }
+}
+
+impl FromStr for Data {
+    type Err = ParseError;
+
+    /// Parse the input into a Data structure
+    /// Example: [1,2,3] -> List([Number(1), Number(2), Number(3)])
+    /// Example: 1 -> Number(1)
+    /// Example: [1,[2,3]] -> List([Number(1), List([Number(2), Number(3)])])
+    /// Example: [[[[3]]]] -> List([List([List([List([Number(3)])])])])
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(inner) = s.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            let mut list = Vec::new();
+            let mut start = 0;
+            let mut depth = 0;
+            for (i, c) in inner.char_indices() {
+                if c == '[' {
+                    depth += 1;
+                } else if c == ']' {
+                    depth -= 1;
+                }
+                if depth < 0 {
+                    return Err(ParseError::expected("[ before ]").at(s, &inner[i..]));
+                }
+                if depth == 0 && c == ',' {
+                    list.push(parse_item(s, &inner[start..i])?);
+                    start = i + 1;
+                }
+            }
+            if depth > 0 {
+                return Err(ParseError::expected("]").at(s, &s[s.len()..]));
+            }
+            if start < inner.len() {
+                list.push(parse_item(s, &inner[start..])?);
+            }
+            Ok(Data::List(list))
+        } else {
+            Ok(Data::Number(number(s, s, "<n> or [<packets>]")?))
+        }
+    }
+}
+
+/// Parse an item of the packet, which is either a number or another list
+fn parse_item(packet: &str, item: &str) -> Result<Data, ParseError> {
+    let item = item.trim();
+    item.parse().map_err(|err: ParseError| err.at(packet, item))
+}
+
+fn parse_two_packets(text: &str) -> Result<(Data, Data), ParseError> {
+    let mut packets = text.lines().enumerate();
+    let mut next_packet = || match packets.next() {
+        Some((index, packet)) => packet
+            .parse()
+            .map_err(|err: ParseError| err.offset_line(index)),
+        None => Err(ParseError::expected("<packet>").offset_line(text.lines().count())),
+    };
+    let packet1 = next_packet()?;
+    let packet2 = next_packet()?;
+    Ok((packet1, packet2))
+}
+
+fn parse_input(text: &str) -> Result<Vec<(Data, Data)>, ParseError> {
+    blocks_with(text, parse_two_packets)
+}
+
+fn process_part1(pairs: &[(Data, Data)]) -> i32 {
+    pairs
+        .iter()
+        .enumerate()
+        .filter(|(_, (left, right))| in_order(left, right).is_correct())
+        .map(|(i, _)| i as i32 + 1)
+        .sum()
+}
+
+fn process_part2(pairs: &[(Data, Data)]) -> i32 {
+    let mut packets = pairs
+        .iter()
+        .flat_map(|(left, right)| [left.clone(), right.clone()])
+        .collect::<Vec<Data>>();
+
+    // Divider packets
+    let divider_packet_1 = "[[2]]".parse().unwrap();
+    let divider_packet_2 = "[[6]]".parse().unwrap();
+    packets.push(divider_packet_1);
+    packets.push(divider_packet_2);
+
+    // Sort the packets
+    packets.sort_by(|left, right| in_order(left, right).to_ordering());
+
+    // Find the index of the divider packets
+    let divider_packet_1 = "[[2]]".parse().unwrap();
+    let divider_packet_2 = "[[6]]".parse().unwrap();
+    let divider_index_1 = packets.iter().position(|p| p == &divider_packet_1).unwrap() + 1;
+    let divider_index_2 = packets.iter().position(|p| p == &divider_packet_2).unwrap() + 1;
+
+    (divider_index_1 * divider_index_2) as i32
+}
+
+#[derive(Debug, PartialEq)]
+enum OrderingResult {
+    Correct,
+    Inconclusive,
+    Incorrect,
+}
+
+impl OrderingResult {
+    fn is_correct(&self) -> bool {
+        !matches!(self, OrderingResult::Incorrect)
+    }
+
+    fn to_ordering(&self) -> Ordering {
+        match self {
+            Correct => Less,
+            Inconclusive => Less,
+            Incorrect => Greater,
+        }
+    }
+}
+
+impl Not for OrderingResult {
+    type Output = bool;
+
+    fn not(self) -> Self::Output {
+        !self.is_correct()
+    }
+}
+
+/// Check if two packets are in order.
+/// When comparing two values, the first value is called left and the second value is called right. Then:
+///  - If both values are integers, the lower integer should come first. If the left integer is lower than the right integer, the inputs are in the right order. If the left integer is higher than the right integer, the inputs are not in the right order. Otherwise, the inputs are the same integer; continue checking the next part of the input.
+///  - If both values are lists, compare the first value of each list, then the second value, and so on. If the left list runs out of items first, the inputs are in the right order. If the right list runs out of items first, the inputs are not in the right order. If the lists are the same length and no comparison makes a decision about the order, continue checking the next part of the input.
+///  - If exactly one value is an integer, convert the integer to a list which contains that integer as its only value, then retry the comparison. For example, if comparing [0,0,0] and 2, convert the right value to [2] (a list containing 2); the result is then found by instead comparing [0,0,0] and [2].
+fn in_order(left: &Data, right: &Data) -> OrderingResult {
+    match (left, right) {
+        (Data::Number(l), Data::Number(r)) => match l.cmp(r) {
+            Less => Correct,
+            Equal => Inconclusive,
+            Greater => Incorrect,
+        },
+        (Data::List(l), Data::List(r)) => {
+            let mut l = l.iter();
+            let mut r = r.iter();
+            loop {
+                match (l.next(), r.next()) {
+                    (Some(l), Some(r)) => {
+                        let result = in_order(l, r);
+                        match result {
+                            Correct => return result,
+                            Inconclusive => continue,
+                            Incorrect => return result,
+                        }
+                    }
+                    (Some(_), None) => return Incorrect,
+                    (None, Some(_)) => return Correct,
+                    (None, None) => return Inconclusive,
+                }
+            }
+        }
+        (Data::Number(n), Data::List(_)) => in_order(&Data::List(vec![Data::Number(*n)]), right),
+        (Data::List(_), Data::Number(n)) => in_order(left, &Data::List(vec![Data::Number(*n)])),
+    }
+}
+
+#[test]
+fn test_packet_parsing() {
+    let packet = "[1,2,3]".parse::<Data>().unwrap();
+    assert_eq!(
+        packet,
+        Data::List(vec![Data::Number(1), Data::Number(2), Data::Number(3)])
+    );
+
+    let packet = "1".parse::<Data>().unwrap();
+    assert_eq!(packet, Data::Number(1));
+
+    let packet = "[1,[2,3]]".parse::<Data>().unwrap();
+    assert_eq!(
+        packet,
+        Data::List(vec![
+            Data::Number(1),
+            Data::List(vec![Data::Number(2), Data::Number(3)])
+        ])
+    );
+
+    let packet = "[1,[2,[3,[4,[5,6,0]]]],8,9]".parse::<Data>().unwrap();
+    assert_eq!(
+        packet,
+        Data::List(vec![
+            Data::Number(1),
+            Data::List(vec![
+                Data::Number(2),
+                Data::List(vec![
+                    Data::Number(3),
+                    Data::List(vec![
+                        Data::Number(4),
+                        Data::List(vec![Data::Number(5), Data::Number(6), Data::Number(0),])
+                    ])
+                ])
+            ]),
+            Data::Number(8),
+            Data::Number(9),
+        ])
+    );
+
+    let packet = "[[[]]]".parse::<Data>().unwrap();
+    assert_eq!(
+        packet,
+        Data::List(vec![Data::List(vec![Data::List(vec![])])])
+    );
+}
+
+#[test]
+fn test_in_order_all_items_smaller() {
+    let left = "[1, 1, 3, 1, 1]".parse::<Data>().unwrap();
+    let right = "[1, 1, 5, 1, 1]".parse::<Data>().unwrap();
+    assert!(in_order(&left, &right));
+}
+
+#[test]
+fn test_in_order_left_array_is_smaller() {
+    let left = "[[1],[2,3,4]]".parse::<Data>().unwrap();
+    let right = "[[1],4]".parse::<Data>().unwrap();
+    assert!(in_order(&left, &right));
+
+    let left = "[[4,4],4,4]".parse::<Data>().unwrap();
+    let right = "[[4,4],4,4,4]".parse::<Data>().unwrap();
+    assert!(in_order(&left, &right));
+
+    let left = "[]".parse::<Data>().unwrap();
+    let right = "[3]".parse::<Data>().unwrap();
+    assert!(in_order(&left, &right));
+}
+
+#[test]
+fn test_not_in_order_left_items_larger() {
+    let left = "[9]".parse::<Data>().unwrap();
+    let right = "[[8,7,6]]".parse::<Data>().unwrap();
+    assert!(!in_order(&left, &right));
+
+    let left = "[1,[2,[3,[4,[5,6,7]]]],8,9]".parse::<Data>().unwrap();
+    let right = "[1,[2,[3,[4,[5,6,0]]]],8,9]".parse::<Data>().unwrap();
+    assert!(!in_order(&left, &right));
+}
+
+#[test]
+fn test_not_in_order_left_array_larger() {
+    let left = "[7,7,7,7]".parse::<Data>().unwrap();
+    let right = "[7,7,7]".parse::<Data>().unwrap();
+    assert!(!in_order(&left, &right));
+
+    let left = "[[[]]]".parse::<Data>().unwrap();
+    let right = "[[]]".parse::<Data>().unwrap();
+    assert!(!in_order(&left, &right));
+}
+
+#[test]
+fn test_example() {
+    let input = common::parse_input::<Day13>(include_str!("../example.txt")).unwrap();
+    assert_eq!(Day13::part1(&input), Answer::from(13));
+    assert_eq!(Day13::part2(&input), Answer::from(140));
+}
@@ -0,0 +1,44 @@
+//! Random sensor reports, to check the solutions against each other on more than the example.
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// A small sensor report in the format of the puzzle input, around the area of the example. Every
+/// sensor reports the beacon that is closest to it, without ties, like the puzzle promises. The
+/// same seed always gives the same report.
+pub fn input(seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let point = |rng: &mut StdRng| (rng.gen_range(-5..=25), rng.gen_range(-5..=25));
+    let distance = |a: (i32, i32), b: (i32, i32)| (a.0 - b.0).abs() + (a.1 - b.1).abs();
+
+    loop {
+        let beacons = (0..rng.gen_range(1..=6))
+            .map(|_| point(&mut rng))
+            .collect::<Vec<_>>();
+
+        let mut lines = Vec::new();
+        let mut sensors = Vec::new();
+        for _ in 0..rng.gen_range(1..=10) {
+            let sensor = point(&mut rng);
+            if beacons.contains(&sensor) || sensors.contains(&sensor) {
+                continue;
+            }
+
+            let closest = beacons.iter().map(|b| distance(sensor, *b)).min().unwrap();
+            let mut closest_beacons = beacons.iter().filter(|b| distance(sensor, **b) == closest);
+            let beacon = closest_beacons.next().unwrap();
+            if closest_beacons.next().is_some() {
+                continue;
+            }
+
+            sensors.push(sensor);
+            lines.push(format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+                sensor.0, sensor.1, beacon.0, beacon.1
+            ));
+        }
+
+        if !lines.is_empty() {
+            return lines.join("\n");
+        }
+    }
+}
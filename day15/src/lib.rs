@@ -0,0 +1,401 @@
+use common::parse::{lines, number};
+use common::{Answer, ParseError, Solution};
+use rayon::prelude::*;
+use std::str::FromStr;
+
+pub mod generate;
+
+pub struct Day15;
+
+common::solution_functions!(Day15);
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = Cave;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        text.parse()
+    }
+
+    fn part1(cave: &Self::Input) -> Answer {
+        process_part1(cave).into()
+    }
+
+    fn part2(cave: &Self::Input) -> Answer {
+        process_part2(cave).into()
+    }
+}
+
+// You feel the ground rumble again as the distress signal leads you to a large network of subterranean tunnels. You don't have time to search them all, but you don't need to: your pack contains a set of deployable sensors that you imagine were originally built to locate lost Elves.
+// The sensors aren't very powerful, but that's okay; your handheld device indicates that you're close enough to the source of the distress signal to use them. You pull the emergency sensor system out of your pack, hit the big button on top, and the sensors zoom off down the tunnels.
+// Once a sensor finds a spot it thinks will give it a good reading, it attaches itself to a hard surface and begins monitoring for the nearest signal source beacon. Sensors and beacons always exist at integer coordinates. Each sensor knows its own position and can determine the position of a beacon precisely; however, sensors can only lock on to the one beacon closest to the sensor as measured by the Manhattan distance. (There is never a tie where two beacons are the same distance to a sensor.)
+// None of the detected beacons seem to be producing the distress signal, so you'll need to work out where the distress beacon is by working out where it isn't. For now, keep things simple by counting the positions where a beacon cannot possibly be along just a single row.
+// So, suppose you have an arrangement of beacons and sensors like in the example above and, just in the row where y=10, you'd like to count the number of positions a beacon cannot possibly exist.
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl Point {
+    fn manhattan_distance(&self, other: &Point) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+}
+
+#[derive(Debug)]
+struct Sensor {
+    position: Point,
+    closest_beacon: Beacon,
+    manhattan_distance: i32,
+}
+
+impl FromStr for Sensor {
+    type Err = ParseError;
+
+    /// Parse a sensor from a string
+    /// Example: Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let re = regex::Regex::new(r"Sensor at x=(?P<x>-?\d+), y=(?P<y>-?\d+): closest beacon is at x=(?P<bx>-?\d+), y=(?P<by>-?\d+)").unwrap();
+        let caps = re.captures(s).ok_or_else(|| {
+            ParseError::expected("Sensor at x=<x>, y=<y>: closest beacon is at x=<x>, y=<y>")
+        })?;
+        let x = number(s, &caps["x"], "<x>")?;
+        let y = number(s, &caps["y"], "<y>")?;
+        let bx = number(s, &caps["bx"], "<x>")?;
+        let by = number(s, &caps["by"], "<y>")?;
+
+        let position = Point { x, y };
+        let closest_beacon = Beacon {
+            position: Point { x: bx, y: by },
+        };
+        let manhattan_distance = position.manhattan_distance(&closest_beacon.position);
+
+        Ok(Sensor {
+            position,
+            closest_beacon,
+            manhattan_distance,
+        })
+    }
+}
+
+impl Sensor {
+    fn get_all_points_just_outside_reach(&self) -> Vec<Point> {
+        let x = self.position.x;
+        let y = self.position.y;
+        let manhattan_distance = self.manhattan_distance;
+
+        (0..(manhattan_distance + 1))
+            .flat_map(|d| {
+                [
+                    Point {
+                        x: x - manhattan_distance - 1 + d,
+                        y: y - d,
+                    },
+                    Point {
+                        x: x - d,
+                        y: y + manhattan_distance + 1 - d,
+                    },
+                    Point {
+                        x: x + manhattan_distance + 1 - d,
+                        y: y + d,
+                    },
+                    Point {
+                        x: x + d,
+                        y: y - manhattan_distance - 1 + d,
+                    },
+                ]
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+struct Beacon {
+    position: Point,
+}
+
+#[derive(Debug)]
+pub struct Cave {
+    sensors: Vec<Sensor>,
+}
+
+impl FromStr for Cave {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let sensors: Vec<Sensor> = lines(s)?;
+        if sensors.is_empty() {
+            return Err(ParseError::expected(
+                "Sensor at x=<x>, y=<y>: closest beacon is at x=<x>, y=<y>",
+            ));
+        }
+        Ok(Cave { sensors })
+    }
+}
+
+impl Cave {
+    #[cfg(test)]
+    // The minimum x is defined as the minimum x of all sensors minus its manhattan distance
+    fn min_x(&self) -> i32 {
+        self.sensors
+            .iter()
+            .map(|s| s.position.x - s.manhattan_distance)
+            .min()
+            .unwrap()
+    }
+
+    #[cfg(test)]
+    // The maximum x is defined as the maximum x of all sensors plus its manhattan distance
+    fn max_x(&self) -> i32 {
+        self.sensors
+            .iter()
+            .map(|s| s.position.x + s.manhattan_distance)
+            .max()
+            .unwrap()
+    }
+
+    #[allow(dead_code)]
+    // The minimum y is defined as the minimum y of all sensors minus its manhattan distance
+    fn min_y(&self) -> i32 {
+        self.sensors
+            .iter()
+            .map(|s| s.position.y - s.manhattan_distance)
+            .min()
+            .unwrap()
+    }
+
+    #[allow(dead_code)]
+    // The maximum y is defined as the maximum y of all sensors plus its manhattan distance
+    fn max_y(&self) -> i32 {
+        self.sensors
+            .iter()
+            .map(|s| s.position.y + s.manhattan_distance)
+            .max()
+            .unwrap()
+    }
+
+    #[cfg(test)]
+    #[allow(dead_code)]
+    fn print(&self) {
+        self.print_with_bounds(self.min_x(), self.max_x(), self.min_y(), self.max_y())
+    }
+
+    #[allow(dead_code)]
+    // Print the cave. The sensors are represented by a 'S' and the beacons by a 'B'
+    // Empty space is represented by a '.'
+    // Since the sensors are not able to detect the beacons outside of their manhattan distance, we
+    // print a '#' for the points inside the manhattan distance
+    fn print_with_bounds(&self, min_x: i32, max_x: i32, min_y: i32, max_y: i32) {
+        for y in min_y..=max_y {
+            for x in min_x..=max_x {
+                let point = Point { x, y };
+                if self.sensors.iter().any(|s| s.position == point) {
+                    eprint!("S");
+                } else if self
+                    .sensors
+                    .iter()
+                    .any(|s| s.closest_beacon.position == point)
+                {
+                    eprint!("B");
+                } else if self
+                    .sensors
+                    .iter()
+                    .any(|s| point.manhattan_distance(&s.position) <= s.manhattan_distance)
+                {
+                    eprint!("#");
+                } else {
+                    eprint!(".");
+                }
+            }
+            eprintln!();
+        }
+    }
+}
+
+fn process_part1(cave: &Cave) -> i32 {
+    count_positions_without_beacon(cave, 2000000)
+}
+
+fn count_positions_without_beacon(cave: &Cave, y: i32) -> i32 {
+    // The reach of every sensor covers a single range of the row, which gets narrower the further
+    // the sensor is from the row
+    let mut ranges = cave
+        .sensors
+        .iter()
+        .filter_map(|s| {
+            let reach = s.manhattan_distance - (s.position.y - y).abs();
+            (reach >= 0).then_some((s.position.x - reach, s.position.x + reach))
+        })
+        .collect::<Vec<_>>();
+    ranges.sort();
+
+    // Count every covered position once, even where the ranges overlap
+    let mut covered = 0;
+    let mut covered_until = i32::MIN;
+    for (from, to) in ranges {
+        let from = from.max(covered_until.saturating_add(1));
+        if to >= from {
+            covered += to - from + 1;
+            covered_until = to;
+        }
+    }
+
+    // The sensors and beacons on the row are covered by their own sensor, which doesn't count
+    // them. They only count when another sensor covers them as well
+    let mut taken = cave
+        .sensors
+        .iter()
+        .flat_map(|s| [s.position, s.closest_beacon.position])
+        .filter(|point| point.y == y)
+        .map(|point| point.x)
+        .collect::<Vec<_>>();
+    taken.sort();
+    taken.dedup();
+    let uncounted = taken
+        .into_iter()
+        .map(|x| Point { x, y })
+        .filter(|point| {
+            !cave.sensors.iter().any(|s| {
+                s.position != *point
+                    && s.closest_beacon.position != *point
+                    && point.manhattan_distance(&s.position) <= s.manhattan_distance
+            })
+        })
+        .count();
+
+    covered - uncounted as i32
+}
+
+/// Counts the positions of the row one by one, as a reference for the ranges above.
+#[cfg(test)]
+fn count_positions_without_beacon_slow(cave: &Cave, y: i32) -> i32 {
+    // Count the number of points inside the manhattan distance of all sensors at the given row
+    let min_x = cave.min_x();
+    let max_x = cave.max_x();
+
+    (min_x..=max_x)
+        .map(|x| Point { x, y })
+        .map(|point| {
+            i32::from(cave.sensors.iter().any(|s| {
+                s.position != point
+                    && s.closest_beacon.position != point
+                    && point.manhattan_distance(&s.position) <= s.manhattan_distance
+            }))
+        })
+        .sum::<i32>()
+}
+
+// --- Part Two ---
+// Your handheld device indicates that the distress signal is coming from a beacon nearby. The distress beacon is not detected by any sensor, but the distress beacon must have x and y coordinates each no lower than 0 and no larger than 4000000.
+// To isolate the distress beacon's signal, you need to determine its tuning frequency, which can be found by multiplying its x coordinate by 4000000 and then adding its y coordinate.
+// In the example above, the search space is smaller: instead, the x and y coordinates can each be at most 20. With this reduced search area, there is only a single position that could have a beacon: x=14, y=11. The tuning frequency for this distress beacon is 56000011.
+// Find the only possible position for the distress beacon. What is its tuning frequency?
+
+fn find_empty_space_within_bounds(
+    cave: &Cave,
+    lower_bound: i32,
+    upper_bound: i32,
+) -> Option<Point> {
+    // Get all the bordering points just outside the manhattan distance of all sensors (+1)
+    // Then filter out the points that are outside of the bounds
+    // Then filter out the points that are inside the manhattan distance of any sensor
+    // If there is at least one point, return it
+
+    cave.sensors
+        .par_iter()
+        .flat_map(|sensor| sensor.get_all_points_just_outside_reach())
+        .filter(|point| {
+            point.x >= lower_bound
+                && point.x <= upper_bound
+                && point.y >= lower_bound
+                && point.y <= upper_bound
+        })
+        .filter(|point| {
+            !cave
+                .sensors
+                .iter()
+                .any(|s| point.manhattan_distance(&s.position) <= s.manhattan_distance)
+        })
+        .find_first(|point| {
+            !cave
+                .sensors
+                .iter()
+                .any(|s| point.manhattan_distance(&s.position) <= s.manhattan_distance)
+        })
+}
+
+fn process_part2(cave: &Cave) -> i64 {
+    tuning_frequency(cave, 4000000)
+}
+
+fn tuning_frequency(cave: &Cave, upper_bound: i32) -> i64 {
+    let lower_bound = 0;
+
+    let beacon_point = find_empty_space_within_bounds(cave, lower_bound, upper_bound);
+
+    if let Some(beacon_point) = beacon_point {
+        (beacon_point.x as i64) * 4000000 + (beacon_point.y as i64)
+    } else {
+        panic!("No beacon point found");
+    }
+}
+
+#[test]
+fn test_example() {
+    // The example asks about row 10 and a search area of 20 instead of the real 2000000 and
+    // 4000000, so it runs the parts with those numbers.
+    let cave = common::parse_input::<Day15>(include_str!("../example.txt")).unwrap();
+    assert_eq!(count_positions_without_beacon(&cave, 10), 26);
+    assert_eq!(tuning_frequency(&cave, 20), 56000011);
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #![proptest_config(proptest::test_runner::Config::with_cases(128))]
+
+    #[test]
+    fn test_rows_match_a_scan_of_the_row(
+        seed in proptest::prelude::any::<u64>(),
+        y in -5..25,
+    ) {
+        let cave = common::parse_input::<Day15>(&generate::input(seed)).unwrap();
+        proptest::prop_assert_eq!(
+            count_positions_without_beacon(&cave, y),
+            count_positions_without_beacon_slow(&cave, y)
+        );
+    }
+
+    #[test]
+    fn test_empty_space_matches_a_scan_of_the_area(seed in proptest::prelude::any::<u64>()) {
+        let cave = common::parse_input::<Day15>(&generate::input(seed)).unwrap();
+        let empty = (0..=20)
+            .flat_map(|y| (0..=20).map(move |x| Point { x, y }))
+            .filter(|point| {
+                !cave
+                    .sensors
+                    .iter()
+                    .any(|s| point.manhattan_distance(&s.position) <= s.manhattan_distance)
+            })
+            .collect::<Vec<_>>();
+
+        // With more than one empty position it can find any of them, but it needs to find the
+        // only one like in the puzzle
+        let found = find_empty_space_within_bounds(&cave, 0, 20);
+        match empty.as_slice() {
+            [] => proptest::prop_assert_eq!(found, None),
+            [only] => proptest::prop_assert_eq!(found, Some(*only)),
+            _ => proptest::prop_assert!(found.is_none_or(|point| empty.contains(&point))),
+        }
+    }
+}
+
+#[test]
+fn test_no_sensors() {
+    assert_eq!(
+        part2("").unwrap_err().to_string(),
+        "day15: expected \"Sensor at x=<x>, y=<y>: closest beacon is at x=<x>, y=<y>\""
+    );
+}
@@ -0,0 +1,68 @@
+//! Random valve scans, to check the solutions against each other on more than the example.
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+/// A small scan in the format of the puzzle input. Like in the puzzle, we start at valve AA which
+/// releases no pressure, every valve can be reached and the tunnels go both ways. The same seed
+/// always gives the same scan.
+pub fn input(seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut names = vec!["AA".to_string()];
+    let count = rng.gen_range(2..=8);
+    while names.len() < count {
+        let name = (0..2)
+            .map(|_| rng.gen_range(b'A'..=b'Z') as char)
+            .collect::<String>();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    names.shuffle(&mut rng);
+
+    // Connect every valve to one before it, so they can all be reached, and add a few shortcuts
+    let mut tunnels = vec![Vec::new(); count];
+    let mut connect = |a: usize, b: usize| {
+        if a != b && !tunnels[a].contains(&b) {
+            tunnels[a].push(b);
+            tunnels[b].push(a);
+        }
+    };
+    for valve in 1..count {
+        connect(valve, rng.gen_range(0..valve));
+    }
+    for _ in 0..rng.gen_range(0..count) {
+        connect(rng.gen_range(0..count), rng.gen_range(0..count));
+    }
+
+    names
+        .iter()
+        .zip(&tunnels)
+        .map(|(name, tunnels)| {
+            let flow_rate = if name == "AA" || rng.gen_bool(0.3) {
+                0
+            } else {
+                rng.gen_range(1..=25)
+            };
+            let tunnels = tunnels
+                .iter()
+                .map(|valve| names[*valve].as_str())
+                .collect::<Vec<_>>();
+            if tunnels.len() == 1 {
+                format!(
+                    "Valve {} has flow rate={}; tunnel leads to valve {}",
+                    name, flow_rate, tunnels[0]
+                )
+            } else {
+                format!(
+                    "Valve {} has flow rate={}; tunnels lead to valves {}",
+                    name,
+                    flow_rate,
+                    tunnels.join(", ")
+                )
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,586 @@
+use common::parse::{lines, number};
+use common::search::bfs;
+use common::{budget, Answer, ParseError, Solution};
+#[cfg(test)]
+use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
+#[cfg(test)]
+use std::hash::Hash;
+use std::str::FromStr;
+
+pub mod generate;
+
+pub struct Day16;
+
+common::solution_functions!(Day16);
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Cave;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        text.parse()
+    }
+
+    fn part1(cave: &Self::Input) -> Answer {
+        process_part1(cave).into()
+    }
+
+    fn part2(cave: &Self::Input) -> Answer {
+        process_part2(cave).into()
+    }
+}
+
+// --- Day 16: Proboscidea Volcanium ---
+// The sensors have led you to the origin of the distress signal: yet another handheld device, just like the one the Elves gave you. However, you don't see any Elves around; instead, the device is surrounded by elephants! They must have gotten lost in these tunnels, and one of the elephants apparently figured out how to turn on the distress signal.
+// The ground rumbles again, much stronger this time. What kind of cave is this, exactly? You scan the cave with your handheld device; it reports mostly igneous rock, some ash, pockets of pressurized gas, magma... this isn't just a cave, it's a volcano!
+// You need to get the elephants out of here, quickly. Your device estimates that you have 30 minutes before the volcano erupts, so you don't have time to go back out the way you came in.
+// You scan the cave for other options and discover a network of pipes and pressure-release valves. You aren't sure how such a system got into a volcano, but you don't have time to complain; your device produces a report (your puzzle input) of each valve's flow rate if it were opened (in pressure per minute) and the tunnels you could use to move between the valves.
+// There's even a valve in the room you and the elephants are currently standing in labeled AA. You estimate it will take you one minute to open a single valve and one minute to follow any tunnel from one valve to another. What is the most pressure you could release?
+// For example, suppose you had the following scan output:
+//
+// Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+// Valve BB has flow rate=13; tunnels lead to valves CC, AA
+// Valve CC has flow rate=2; tunnels lead to valves DD, BB
+// Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+// Valve EE has flow rate=3; tunnels lead to valves FF, DD
+// Valve FF has flow rate=0; tunnels lead to valves EE, GG
+// Valve GG has flow rate=0; tunnels lead to valves FF, HH
+// Valve HH has flow rate=22; tunnel leads to valve GG
+// Valve II has flow rate=0; tunnels lead to valves AA, JJ
+// Valve JJ has flow rate=21; tunnel leads to valve II
+// All of the valves begin closed. You start at valve AA, but it must be damaged or jammed or something: its flow rate is 0, so there's no point in opening it. However, you could spend one minute moving to valve BB and another minute opening it; doing so would release pressure during the remaining 28 minutes at a flow rate of 13, a total eventual pressure release of 28 * 13 = 364. Then, you could spend your third minute moving to valve CC and your fourth minute opening it, providing an additional 26 minutes of eventual pressure release at a flow rate of 2, or 52 total pressure released by valve CC.
+//
+// Making your way through the tunnels like this, you could probably open many or all of the valves by the time 30 minutes have elapsed. However, you need to release as much pressure as possible, so you'll need to be methodical.
+
+#[derive(Debug)]
+struct Valve {
+    name: String,
+    flow_rate: u64,
+    tunnels: Vec<String>,
+}
+
+impl FromStr for Valve {
+    type Err = ParseError;
+
+    /// Parse a valve from a string
+    /// Example: Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, rest) = s
+            .strip_prefix("Valve ")
+            .and_then(|rest| rest.split_once(" has "))
+            .ok_or_else(|| ParseError::expected("Valve <name> has"))?;
+        let name = name.to_string();
+
+        let (flow_rate, rest) = rest
+            .strip_prefix("flow rate=")
+            .and_then(|flow_rate| flow_rate.split_once("; "))
+            .ok_or_else(|| ParseError::expected("flow rate=<n>").at(s, rest))?;
+        let flow_rate = number(s, flow_rate, "flow rate=<n>")?;
+
+        // A single tunnel is written in singular
+        let tunnels = ["tunnels lead to valves ", "tunnel leads to valve "]
+            .iter()
+            .find_map(|prefix| rest.strip_prefix(prefix))
+            .ok_or_else(|| ParseError::expected("tunnels lead to valves <names>").at(s, rest))?
+            .split(", ")
+            .map(|s| s.to_string())
+            .collect();
+        Ok(Valve {
+            name,
+            flow_rate,
+            tunnels,
+        })
+    }
+}
+
+#[test]
+fn test_valve_from_string() {
+    let string = "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB";
+    let valve: Valve = string.parse().unwrap();
+    assert_eq!(valve.name, "AA");
+    assert_eq!(valve.flow_rate, 0);
+    assert_eq!(valve.tunnels, vec!["DD", "II", "BB"]);
+
+    let string = "Valve HH has flow rate=22; tunnel leads to valve GG";
+    let valve: Valve = string.parse().unwrap();
+    assert_eq!(valve.name, "HH");
+    assert_eq!(valve.flow_rate, 22);
+    assert_eq!(valve.tunnels, vec!["GG"]);
+}
+
+#[derive(Debug)]
+pub struct Cave {
+    valves: HashMap<String, Valve>,
+}
+
+impl FromStr for Cave {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let valves: Vec<Valve> = lines(s)?;
+
+        // We start at valve AA, and every tunnel has to lead to a valve that exists
+        let names = valves
+            .iter()
+            .map(|v| v.name.as_str())
+            .collect::<HashSet<_>>();
+        if !names.contains("AA") {
+            return Err(ParseError::expected("Valve AA"));
+        }
+        for (index, (line, valve)) in s.lines().zip(&valves).enumerate() {
+            if let Some(tunnel) = valve.tunnels.iter().find(|t| !names.contains(t.as_str())) {
+                let column = line.rfind(tunnel.as_str()).unwrap_or_default() + 1;
+                return Err(ParseError::expected("<name> of a valve")
+                    .at_column(column)
+                    .offset_line(index));
+            }
+        }
+
+        // The search keeps the valves it opened as the bits of a u64
+        let first_too_many = s
+            .lines()
+            .zip(&valves)
+            .enumerate()
+            .filter(|(_, (_, valve))| valve.flow_rate > 0)
+            .nth(MAX_VALVES_WORTH_OPENING);
+        if let Some((index, (line, _))) = first_too_many {
+            let column = line.find("flow rate=").unwrap() + 1;
+            let expected = format!(
+                "flow rate=0, as at most {} valves can release pressure",
+                MAX_VALVES_WORTH_OPENING
+            );
+            return Err(ParseError::expected(expected)
+                .at_column(column)
+                .offset_line(index));
+        }
+
+        let valves = valves
+            .into_iter()
+            .map(|v: Valve| (v.name.clone(), v))
+            .collect();
+        Ok(Cave { valves })
+    }
+}
+
+#[cfg(test)]
+struct Choice {
+    name: String,
+    // The total amount that can be released after the given time.
+    total_released: u64,
+    opened: HashSet<String>,
+}
+
+#[cfg(test)]
+fn calculate_new_choices(cave: &Cave, start_point: &Choice, time_left: u64) -> Vec<Choice> {
+    let mut new_choices = Vec::new();
+
+    let valve = cave.valves.get(&start_point.name).unwrap();
+    if valve.flow_rate > 0 && !start_point.opened.contains(&valve.name) {
+        // Open this valve.
+        let mut opened = start_point.opened.clone();
+        opened.insert(valve.name.clone());
+
+        let new_release = start_point.total_released + time_left * valve.flow_rate;
+
+        let name = valve.name.clone();
+
+        new_choices.push(Choice {
+            name,
+            total_released: new_release,
+            opened,
+        });
+    }
+    for tunnel in &valve.tunnels {
+        new_choices.push(Choice {
+            name: tunnel.clone(),
+            total_released: start_point.total_released,
+            opened: start_point.opened.clone(),
+        });
+    }
+
+    new_choices
+}
+
+#[cfg(test)]
+fn maximal_released(
+    cave: &Cave,
+    opened: HashSet<String>,
+    current_released: u64,
+    time_left: u64,
+) -> u64 {
+    // Calculate the maximal amount of pressure that can be released in the given time.
+    cave.valves
+        .iter()
+        .filter(|(_, v)| v.flow_rate > 0 && !opened.contains(&v.name))
+        .map(|(_, v)| v.flow_rate)
+        .sum::<u64>()
+        * time_left
+        + current_released
+}
+
+/// The opened valves in a fixed order, so choices that opened the same valves get the same key.
+#[cfg(test)]
+fn opened_key(opened: &HashSet<String>) -> Vec<String> {
+    let mut opened = opened.iter().cloned().collect::<Vec<_>>();
+    opened.sort();
+    opened
+}
+
+/// Keeps the choice that released the most of all choices with the same key. Choices that are at
+/// the same valves with the same valves opened can only release more from here on if they have
+/// released more already.
+#[cfg(test)]
+fn keep_most_released<K: Hash + Eq, C>(
+    choices: Vec<(K, C)>,
+    released: impl Fn(&C) -> u64,
+) -> HashMap<K, C> {
+    let mut kept: HashMap<K, C> = HashMap::new();
+    for (key, choice) in choices {
+        if kept
+            .get(&key)
+            .is_none_or(|known| released(known) < released(&choice))
+        {
+            kept.insert(key, choice);
+        }
+    }
+    kept
+}
+
+/// Goes through the first part minute by minute, as a reference for the search over the valves
+/// worth opening.
+#[cfg(test)]
+fn process_part1_slow(cave: &Cave) -> i64 {
+    let mut choices = HashMap::new();
+    choices.insert(
+        ("AA".to_string(), Vec::new()),
+        Choice {
+            name: "AA".to_string(),
+            total_released: 0,
+            opened: HashSet::new(),
+        },
+    );
+
+    let max_time = 30;
+
+    for minute in 0..max_time {
+        budget::check(|| format!("{} minutes, {} choices", minute, choices.len()));
+        let new_choices = choices
+            .into_par_iter()
+            .flat_map(|(_, choice)| {
+                calculate_new_choices(cave, &choice, max_time - minute - 1)
+                    .into_par_iter()
+                    .collect::<Vec<Choice>>()
+            })
+            .map(|c| ((c.name.clone(), opened_key(&c.opened)), c))
+            .collect::<Vec<_>>();
+        choices = keep_most_released(new_choices, |c| c.total_released);
+    }
+
+    choices
+        .values()
+        .map(|c| c.total_released as i64)
+        .max()
+        .unwrap()
+}
+
+// --- Part Two ---
+// You're worried that even with an optimal approach, the pressure released won't be enough. What if you got one of the elephants to help you?
+// It would take you 4 minutes to teach an elephant how to open the right valves in the right order, leaving you with only 26 minutes to actually execute your plan. Would having two of you working together be better, even if it means having less time? (Assume that you teach the elephant before opening any valves yourself, giving you both the same full 26 minutes.)
+
+#[cfg(test)]
+struct Choice2 {
+    you: String,
+    elephant: String,
+    // The total amount that can be released after the given time.
+    total_released: u64,
+    opened: HashSet<String>,
+}
+
+#[cfg(test)]
+fn compute_new_choices2(cave: &Cave, start_point: &Choice2, time_left: u64) -> Vec<Choice2> {
+    let mut new_choices = Vec::new();
+    let start_point_you = Choice {
+        name: start_point.you.clone(),
+        total_released: 0,
+        opened: start_point.opened.clone(),
+    };
+    let start_point_elephant = Choice {
+        name: start_point.elephant.clone(),
+        total_released: 0,
+        opened: start_point.opened.clone(),
+    };
+
+    let new_choices_for_you = calculate_new_choices(cave, &start_point_you, time_left);
+
+    let new_choices_for_elephant = calculate_new_choices(cave, &start_point_elephant, time_left);
+
+    for new_choice_you in new_choices_for_you {
+        for new_choice_elephant in &new_choices_for_elephant {
+            // We need to check if the elephant is going to open a valve that the you is going to open.
+
+            if new_choice_you
+                .opened
+                .intersection(&new_choice_elephant.opened)
+                .count()
+                > start_point.opened.len()
+            {
+                continue;
+            }
+
+            let mut opened = new_choice_you.opened.clone();
+            opened.extend(new_choice_elephant.opened.clone());
+
+            let new_release = start_point.total_released
+                + new_choice_you.total_released
+                + new_choice_elephant.total_released;
+
+            new_choices.push(Choice2 {
+                you: new_choice_you.name.clone(),
+                elephant: new_choice_elephant.name.clone(),
+                total_released: new_release,
+                opened,
+            });
+        }
+    }
+
+    new_choices
+}
+
+/// Goes through the second part minute by minute, as a reference for the search over the valves
+/// worth opening. Takes far too much memory for a real puzzle input.
+#[cfg(test)]
+fn process_part2_slow(cave: &Cave) -> i64 {
+    let mut choices = HashMap::new();
+    choices.insert(
+        ("AA".to_string(), "AA".to_string(), Vec::new()),
+        Choice2 {
+            you: "AA".to_string(),
+            elephant: "AA".to_string(),
+            total_released: 0,
+            opened: HashSet::new(),
+        },
+    );
+
+    let max_time = 26;
+
+    for minute in 0..max_time {
+        budget::check(|| format!("{} minutes, {} choices", minute, choices.len()));
+        tracing::debug!(minute, choices = choices.len(), "choices left");
+
+        let max_released = choices
+            .par_iter()
+            .map(|(_, c)| c.total_released as i64)
+            .max()
+            .unwrap();
+
+        let new_choices = choices
+            .into_par_iter()
+            .flat_map(|(_, choice)| {
+                compute_new_choices2(cave, &choice, max_time - minute - 1)
+                    .into_par_iter()
+                    .collect::<Vec<Choice2>>()
+            })
+            // Only if the maximal amount of pressure that can be released is higher than the current
+            // maximal amount of pressure that can be released, we need to keep the choice.
+            .filter(|c| {
+                maximal_released(
+                    cave,
+                    c.opened.clone(),
+                    c.total_released,
+                    max_time - minute - 1,
+                ) as i64
+                    >= max_released
+            })
+            .map(|c| {
+                let key = (c.you.clone(), c.elephant.clone(), opened_key(&c.opened));
+                (key, c)
+            })
+            .collect::<Vec<_>>();
+        choices = keep_most_released(new_choices, |c| c.total_released);
+    }
+
+    choices
+        .values()
+        .map(|c| c.total_released as i64)
+        .max()
+        .unwrap()
+}
+
+// Most valves release no pressure at all, so they are only walked through. Walking straight to the
+// next valve worth opening leaves a much smaller graph to search than going minute by minute.
+
+/// How many valves worth opening the search can keep track of.
+const MAX_VALVES_WORTH_OPENING: usize = u64::BITS as usize;
+
+/// The valves worth opening, with the minutes it takes to walk from one to another.
+struct Network {
+    flow_rates: Vec<u64>,
+    // The minutes between every pair of valves worth opening, followed by AA where we start.
+    // Valves that can't be reached from each other have no distance.
+    distances: Vec<Vec<Option<u64>>>,
+}
+
+impl Network {
+    #[tracing::instrument(name = "network", skip_all)]
+    fn new(cave: &Cave) -> Self {
+        let mut valves = cave
+            .valves
+            .values()
+            .filter(|v| v.flow_rate > 0)
+            .collect::<Vec<_>>();
+        valves.sort_by(|a, b| a.name.cmp(&b.name));
+        let flow_rates = valves.iter().map(|v| v.flow_rate).collect();
+
+        let mut names = valves.iter().map(|v| v.name.as_str()).collect::<Vec<_>>();
+        names.push("AA");
+        let distances = names
+            .iter()
+            .map(|from| {
+                names
+                    .iter()
+                    .map(|to| {
+                        let search = bfs(
+                            [*from],
+                            |name| {
+                                cave.valves[*name]
+                                    .tunnels
+                                    .iter()
+                                    .map(String::as_str)
+                                    .collect::<Vec<_>>()
+                            },
+                            |name| name == to,
+                        );
+                        search.cost().map(u64::from)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Network {
+            flow_rates,
+            distances,
+        }
+    }
+
+    /// The most pressure that can be released within the given minutes for every set of valves
+    /// that can be opened in time, where bit `i` of a set stands for the `i`th valve worth opening.
+    #[tracing::instrument(skip(self))]
+    fn best_per_opened(&self, minutes: u64) -> HashMap<u64, u64> {
+        let mut best = HashMap::new();
+        self.open_next(self.flow_rates.len(), minutes, 0, 0, &mut best);
+        best
+    }
+
+    fn open_next(
+        &self,
+        position: usize,
+        time_left: u64,
+        opened: u64,
+        released: u64,
+        best: &mut HashMap<u64, u64>,
+    ) {
+        budget::check(|| format!("{} sets of valves opened", best.len()));
+        let best_released = best.entry(opened).or_insert(0);
+        *best_released = (*best_released).max(released);
+
+        for (valve, flow_rate) in self.flow_rates.iter().enumerate() {
+            if opened & (1 << valve) != 0 {
+                continue;
+            }
+            let Some(distance) = self.distances[position][valve] else {
+                continue;
+            };
+
+            // Walking there and opening the valve has to leave time to release some pressure
+            if distance + 1 < time_left {
+                let time_left = time_left - distance - 1;
+                self.open_next(
+                    valve,
+                    time_left,
+                    opened | (1 << valve),
+                    released + time_left * flow_rate,
+                    best,
+                );
+            }
+        }
+    }
+}
+
+fn process_part1(cave: &Cave) -> i64 {
+    let network = Network::new(cave);
+    network.best_per_opened(30).into_values().max().unwrap() as i64
+}
+
+fn process_part2(cave: &Cave) -> i64 {
+    let network = Network::new(cave);
+    let mut best = network.best_per_opened(26).into_iter().collect::<Vec<_>>();
+    best.sort_by_key(|(_, released)| std::cmp::Reverse(*released));
+
+    // You and the elephant each open a different set of valves. Going through the sets from the
+    // most released, the pairs can stop as soon as they can't beat the best pair so far.
+    let _pairs = tracing::info_span!("pairs", sets = best.len()).entered();
+    let mut most_released = 0;
+    for (i, (yours, you_released)) in best.iter().enumerate() {
+        if you_released * 2 <= most_released {
+            break;
+        }
+        budget::check(|| format!("best pair so far releases {}", most_released));
+        for (elephants, elephant_released) in &best[i..] {
+            if you_released + elephant_released <= most_released {
+                break;
+            }
+            if yours & elephants == 0 {
+                most_released = you_released + elephant_released;
+            }
+        }
+    }
+    most_released as i64
+}
+
+#[test]
+fn test_example() {
+    let input = common::parse_input::<Day16>(include_str!("../example.txt")).unwrap();
+    assert_eq!(Day16::part1(&input), Answer::from(1651));
+    assert_eq!(Day16::part2(&input), Answer::from(1707));
+}
+
+#[test]
+fn test_too_many_valves_worth_opening() {
+    let text = (0..=MAX_VALVES_WORTH_OPENING)
+        .map(|i| format!("Valve V{} has flow rate=1; tunnel leads to valve AA", i))
+        .chain(["Valve AA has flow rate=0; tunnel leads to valve V0".to_string()])
+        .collect::<Vec<_>>()
+        .join("\n");
+    assert_eq!(
+        part1(&text).unwrap_err().to_string(),
+        "day16 line 65 column 15: expected \"flow rate=0, as at most 64 valves can release pressure\""
+    );
+}
+
+#[test]
+fn test_choices_that_opened_other_valves_are_kept() {
+    // Some choices end up at the same valve having released as much, with other valves opened.
+    // Keeping either one of them by chance answered 784 about half of the time
+    let text = "Valve FH has flow rate=4; tunnels lead to valves YW, AA
+Valve YW has flow rate=2; tunnel leads to valve FH
+Valve AA has flow rate=0; tunnels lead to valves FH, ZB, OR
+Valve ZB has flow rate=4; tunnel leads to valve AA
+Valve OR has flow rate=20; tunnel leads to valve AA";
+    for _ in 0..10 {
+        assert_eq!(part1(text).unwrap(), Answer::from(788));
+    }
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #![proptest_config(proptest::test_runner::Config::with_cases(32))]
+
+    #[test]
+    fn test_search_matches_going_minute_by_minute(seed in proptest::prelude::any::<u64>()) {
+        let cave = common::parse_input::<Day16>(&generate::input(seed)).unwrap();
+        proptest::prop_assert_eq!(process_part1(&cave), process_part1_slow(&cave));
+        proptest::prop_assert_eq!(process_part2(&cave), process_part2_slow(&cave));
+    }
+}
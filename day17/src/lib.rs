@@ -0,0 +1,382 @@
+use common::{budget, frames, Answer, Direction, ParseError, Point, Solution, SparseGrid};
+use std::fmt::{Display, Formatter};
+
+pub struct Day17;
+
+common::solution_functions!(Day17);
+
+/// The number of rows below the top of the room that are drawn in a frame.
+const FRAME_ROWS: i32 = 30;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input = Vec<Direction>;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        parse_input(text)
+    }
+
+    fn part1(directions: &Self::Input) -> Answer {
+        process_part1(directions).into()
+    }
+
+    fn part2(directions: &Self::Input) -> Answer {
+        process_part2(directions).into()
+    }
+}
+
+// Your handheld device has located an alternative exit from the cave for you and the elephants. The ground is rumbling almost continuously now, but the strange valves bought you some time. It's definitely getting warmer in here, though.
+// The tunnels eventually open into a very tall, narrow chamber. Large, oddly-shaped rocks are falling into the chamber from above, presumably due to all the rumbling. If you can't work out where the rocks will fall next, you might be crushed!
+// The five types of rocks have the following peculiar shapes, where # is rock and . is empty space:
+
+// ####
+//
+// .#.
+// ###
+// .#.
+//
+// ..#
+// ..#
+// ###
+//
+// #
+// #
+// #
+// #
+//
+// ##
+// ##
+// The rocks fall in the order shown above: first the - shape, then the + shape, and so on. Once the end of the list is reached, the same order repeats: the - shape falls first, sixth, 11th, 16th, etc.
+// The rocks don't spin, but they do get pushed around by jets of hot gas coming out of the walls themselves. A quick scan reveals the effect the jets of hot gas will have on the rocks as they fall (your puzzle input).
+// For example, suppose this was the jet pattern in your cave:
+
+// >>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>
+// In jet patterns, < means a push to the Left, while > means a push to the Right. The pattern above means that the jets will push a falling rock Right, then Right, then Right, then Left, then Left, then Right, and so on. If the end of the list is reached, it repeats.
+// The tall, vertical chamber is exactly seven units wide. Each rock appears so that its Left edge is two units away from the Left wall and its bottom edge is three units above the highest rock in the room (or the floor, if there isn't one).
+// After a rock appears, it alternates between being pushed by a jet of hot gas one unit (in the direction indicated by the next symbol in the jet pattern) and then falling one unit down. If any movement would cause any part of the rock to move into the walls, floor, or a stopped rock, the movement instead does not occur. If a downward movement would have caused a falling rock to move into the floor or an already-fallen rock, the falling rock stops where it is (having landed on something) and a new rock immediately begins falling.
+// To prove to the elephants your simulation is accurate, they want to know how tall the tower will get after 2022 rocks have stopped (but before the 2023rd rock begins falling). In this example, the tower of rocks will be 3068 units tall.
+
+/// Parses a single jet of gas, which pushes to the left or the right.
+fn parse_jet(s: &str) -> Result<Direction, ParseError> {
+    match s {
+        "<" => Ok(Direction::Left),
+        ">" => Ok(Direction::Right),
+        _ => Err(ParseError::expected("< or >")),
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Rock {
+    Minus,
+    Plus,
+    InvertedL,
+    Line,
+    Square,
+}
+
+impl Rock {
+    fn local_space_points(&self) -> Vec<Point> {
+        match self {
+            Rock::Minus => vec![
+                Point::new(0, 0),
+                Point::new(1, 0),
+                Point::new(2, 0),
+                Point::new(3, 0),
+            ],
+            Rock::Plus => vec![
+                Point::new(1, 0),
+                Point::new(1, 1),
+                Point::new(1, 2),
+                Point::new(0, 1),
+                Point::new(2, 1),
+            ],
+            Rock::InvertedL => vec![
+                Point::new(0, 0),
+                Point::new(1, 0),
+                Point::new(2, 0),
+                Point::new(2, 1),
+                Point::new(2, 2),
+            ],
+            Rock::Line => vec![
+                Point::new(0, 0),
+                Point::new(0, 1),
+                Point::new(0, 2),
+                Point::new(0, 3),
+            ],
+            Rock::Square => vec![
+                Point::new(0, 0),
+                Point::new(1, 0),
+                Point::new(0, 1),
+                Point::new(1, 1),
+            ],
+        }
+    }
+
+    fn world_space_points(&self, relative: &Point) -> Vec<Point> {
+        self.local_space_points()
+            .iter()
+            .map(|p| *p + *relative)
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+struct Room {
+    width: usize,
+    // All the points where a rock is stopped
+    solids: SparseGrid<()>,
+}
+
+impl Room {
+    fn collides_with_wall(&self, rock: &Rock, point: &Point) -> bool {
+        let points = rock.world_space_points(point);
+        points
+            .iter()
+            .any(|p| p.x < 0 || p.x >= self.width as i32 || self.solids.contains(*p))
+    }
+
+    fn collides_with_bottom(&self, rock: &Rock, point: &Point) -> bool {
+        let points = rock.world_space_points(point);
+        points.iter().any(|p| p.y < 0 || self.solids.contains(*p))
+    }
+
+    /// Draws the top of the room with the falling rock in it. Only the rows near the rock are
+    /// drawn, as the tower soon grows too high to fit on a screen.
+    fn frame(&self, rock: &Rock, rock_position: &Point, caption: &str) -> String {
+        let points = rock.world_space_points(rock_position);
+
+        let max_y = self
+            .solids
+            .bounds()
+            .map_or(0, |(_, max)| max.y)
+            .max(points.iter().map(|p| p.y).max().unwrap_or(0));
+        let min_y = (max_y - FRAME_ROWS).max(-1);
+        let max_x = self.width as i32;
+
+        let mut frame = format!("{}\n", caption);
+        for y in (min_y..=max_y).rev() {
+            for x in -1..=max_x {
+                frame.push(if x == -1 || x == max_x {
+                    '|'
+                } else if y == -1 {
+                    '-'
+                } else if points.contains(&Point::new(x, y)) {
+                    '@'
+                } else if self.solids.contains(Point::new(x, y)) {
+                    '#'
+                } else {
+                    '.'
+                });
+            }
+            frame.push('\n');
+        }
+        frame
+    }
+
+    fn height(&self) -> i32 {
+        self.solids.bounds().map_or(0, |(_, max)| max.y) + 1
+    }
+}
+
+impl Display for Room {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let max_y = self.solids.bounds().map_or(0, |(_, max)| max.y);
+        let max_x = self.width as i32;
+
+        for y in (-1..=max_y).rev() {
+            for x in -1..=max_x {
+                if x == -1 || x == max_x {
+                    write!(f, "|")?;
+                } else if y == -1 {
+                    write!(f, "-")?;
+                } else if self.solids.contains(Point::new(x, y)) {
+                    write!(f, "#")?;
+                } else {
+                    write!(f, ".")?;
+                }
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Direction>, ParseError> {
+    let pattern = input.trim_end();
+    if pattern.is_empty() {
+        return Err(ParseError::expected("<jet pattern>"));
+    }
+
+    pattern
+        .char_indices()
+        .map(|(i, c)| {
+            let jet = &pattern[i..i + c.len_utf8()];
+            parse_jet(jet).map_err(|err| err.at(pattern, jet))
+        })
+        .collect()
+}
+
+fn process_part1(directions: &[Direction]) -> i32 {
+    let mut room = Room {
+        width: 7,
+        solids: SparseGrid::new(),
+    };
+
+    let rock_types = [
+        Rock::Minus,
+        Rock::Plus,
+        Rock::InvertedL,
+        Rock::Line,
+        Rock::Square,
+    ];
+
+    let mut direction_index = 0;
+
+    for i in 0..2022 {
+        budget::check(|| format!("{} rocks fallen", i));
+        let rock = &rock_types[i % 5];
+
+        let max_y = room.solids.bounds().map_or(0, |(_, max)| max.y + 1);
+
+        let mut position = Point::new(2, max_y + 3);
+
+        frames::record(|| room.frame(rock, &position, "Rock begins falling"));
+
+        loop {
+            let direction = directions[direction_index];
+            direction_index = (direction_index + 1) % directions.len();
+
+            let new_position = position.step(direction);
+
+            if !room.collides_with_wall(rock, &new_position) {
+                position = new_position;
+            }
+
+            frames::record(|| {
+                let caption = format!("Jet pushed to the {:?}", direction);
+                room.frame(rock, &position, &caption)
+            });
+
+            let new_position = Point::new(position.x, position.y - 1);
+
+            if room.collides_with_bottom(rock, &new_position) {
+                room.solids.extend(
+                    rock.world_space_points(&position)
+                        .into_iter()
+                        .map(|p| (p, ())),
+                );
+                break;
+            } else {
+                position = new_position;
+            }
+
+            frames::record(|| room.frame(rock, &position, "Rock falls 1 unit"));
+        }
+    }
+
+    room.height()
+}
+
+fn process_part2(directions: &[Direction]) -> usize {
+    let mut room = Room {
+        width: 7,
+        solids: SparseGrid::new(),
+    };
+
+    let rock_types = [
+        Rock::Minus,
+        Rock::Plus,
+        Rock::InvertedL,
+        Rock::Line,
+        Rock::Square,
+    ];
+
+    let mut direction_index = 0;
+
+    let mut deltas = [0; 5000];
+    let mut previous = 0;
+
+    let simulate = tracing::info_span!("simulate", rocks = deltas.len()).entered();
+    for i in 0..5000 {
+        budget::check(|| format!("{} rocks fallen", i));
+        let rock = &rock_types[i % 5];
+
+        let max_y = room.solids.bounds().map_or(0, |(_, max)| max.y + 1);
+
+        let mut position = Point::new(2, max_y + 3);
+
+        frames::record(|| room.frame(rock, &position, "Rock begins falling"));
+
+        loop {
+            let direction = directions[direction_index];
+            direction_index = (direction_index + 1) % directions.len();
+
+            let new_position = position.step(direction);
+
+            if !room.collides_with_wall(rock, &new_position) {
+                position = new_position;
+            }
+
+            frames::record(|| {
+                let caption = format!("Jet pushed to the {:?}", direction);
+                room.frame(rock, &position, &caption)
+            });
+
+            let new_position = Point::new(position.x, position.y - 1);
+
+            if room.collides_with_bottom(rock, &new_position) {
+                room.solids.extend(
+                    rock.world_space_points(&position)
+                        .into_iter()
+                        .map(|p| (p, ())),
+                );
+                break;
+            } else {
+                position = new_position;
+            }
+
+            frames::record(|| room.frame(rock, &position, "Rock falls 1 unit"));
+        }
+
+        deltas[i] = (room.height() - previous) as usize;
+        previous = room.height();
+    }
+
+    simulate.exit();
+
+    let find_pattern = tracing::info_span!("find_pattern").entered();
+    let (offset, size) = (0..1000)
+        .find_map(|offset| {
+            let delta_iter = deltas.iter().skip(offset);
+            let size = (2..=2500).find(|size| {
+                let window = deltas[offset..offset + size].iter().cycle();
+                delta_iter.clone().zip(window).all(|(a, b)| a == b)
+            });
+            size.map(|size| (offset, size))
+        })
+        .expect("No pattern found");
+    tracing::debug!(offset, size, "found a pattern");
+    find_pattern.exit();
+
+    let mut delta_iter = deltas.iter();
+    let mut count = 1_000_000_000_000;
+    let offset_delta = delta_iter.by_ref().take(offset).sum::<usize>();
+    count -= offset;
+    let cycle_deltas: Vec<usize> = delta_iter.take(size).copied().collect();
+    let cycle_delta = cycle_deltas.iter().sum::<usize>();
+    let cycle_count = count / size;
+    count %= size;
+    let remaining_height = cycle_deltas.into_iter().take(count).sum::<usize>();
+    let height: usize = offset_delta + cycle_count * cycle_delta + remaining_height;
+
+    height
+}
+
+#[test]
+fn test_example() {
+    let input = common::parse_input::<Day17>(include_str!("../example.txt")).unwrap();
+    assert_eq!(Day17::part1(&input), Answer::from(3068));
+    assert_eq!(Day17::part2(&input), Answer::from(1514285714288_u64));
+}
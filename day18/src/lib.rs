@@ -0,0 +1,233 @@
+use common::parse::{lines, number};
+use common::search::reachable;
+use common::stream::Streaming;
+use common::{Answer, ParseError, Solution};
+use std::str::FromStr;
+
+pub struct Day18;
+
+common::solution_functions!(Day18);
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input = Grid;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        text.parse()
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        process_part1(grid).into()
+    }
+
+    fn part2(grid: &Self::Input) -> Answer {
+        process_part2(grid).into()
+    }
+}
+
+// --- Day 18: Boiling Boulders ---
+// You and the elephants finally reach fresh air. You've emerged near the base of a large volcano that seems to be actively erupting! Fortunately, the lava seems to be flowing away from you and toward the ocean.
+// Bits of lava are still being ejected toward you, so you're sheltering in the cavern exit a little longer. Outside the cave, you can see the lava landing in a pond and hear it loudly hissing as it solidifies.
+// Depending on the specific compounds in the lava and speed at which it cools, it might be forming obsidian! The cooling rate should be based on the surface area of the lava droplets, so you take a quick scan of a droplet as it flies past you (your puzzle input).
+// Because of how quickly the lava is moving, the scan isn't very good; its resolution is quite low and, as a result, it approximates the shape of the lava droplet with 1x1x1 cubes on a 3D grid, each given as its x,y,z position.
+// To approximate the surface area, count the number of sides of each cube that are not immediately connected to another cube. So, if your scan were only two adjacent cubes like 1,1,1 and 2,1,1, each cube would have a single side covered and five sides exposed, a total surface area of 10 sides.
+// Here's a larger example:
+//
+// 2,2,2
+// 1,2,2
+// 3,2,2
+// 2,1,2
+// 2,3,2
+// 2,2,1
+// 2,2,3
+// 2,2,4
+// 2,2,6
+// 1,2,5
+// 3,2,5
+// 2,1,5
+// 2,3,5
+// In the above example, after counting up all the sides that aren't connected to another cube, the total surface area is 64.
+//
+// What is the surface area of your scanned lava droplet?
+
+impl Streaming for Day18 {
+    // Both parts need every cube, so the cubes are kept, only the text they came from is not
+    type State = Grid;
+
+    fn feed(grid: &mut Self::State, line: &str) -> Result<(), ParseError> {
+        grid.cubes.push(line.parse()?);
+        Ok(())
+    }
+
+    fn finish(grid: Self::State) -> Result<[Answer; 2], ParseError> {
+        grid.check()?;
+        Ok([Day18::part1(&grid), Day18::part2(&grid)])
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Cube {
+    x: i32,
+    y: i32,
+    z: i32,
+}
+
+impl Cube {
+    fn new(x: i32, y: i32, z: i32) -> Self {
+        Cube { x, y, z }
+    }
+
+    fn neighbours(&self) -> CubeNeighboursIterator {
+        CubeNeighboursIterator {
+            cube: *self,
+            index: 0,
+        }
+    }
+}
+
+impl FromStr for Cube {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split(',');
+        let mut next = |expected| match parts.next() {
+            Some(part) => number(s, part, expected),
+            None => Err(ParseError::expected(expected).at(s, &s[s.len()..])),
+        };
+        let x = next("<x>")?;
+        let y = next("<y>")?;
+        let z = next("<z>")?;
+        Ok(Cube { x, y, z })
+    }
+}
+
+struct CubeNeighboursIterator {
+    cube: Cube,
+    index: usize,
+}
+
+impl Iterator for CubeNeighboursIterator {
+    type Item = Cube;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Cube { x, y, z } = self.cube;
+        let result = match self.index {
+            0 => Some(Cube::new(x - 1, y, z)),
+            1 => Some(Cube::new(x + 1, y, z)),
+            2 => Some(Cube::new(x, y - 1, z)),
+            3 => Some(Cube::new(x, y + 1, z)),
+            4 => Some(Cube::new(x, y, z - 1)),
+            5 => Some(Cube::new(x, y, z + 1)),
+            _ => None,
+        };
+        self.index += 1;
+        result
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Grid {
+    cubes: Vec<Cube>,
+}
+
+impl FromStr for Grid {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = Grid { cubes: lines(s)? };
+        grid.check()?;
+        Ok(grid)
+    }
+}
+
+impl Grid {
+    /// The second part searches around the droplet, which needs at least one cube to go around.
+    fn check(&self) -> Result<(), ParseError> {
+        if self.cubes.is_empty() {
+            return Err(ParseError::expected("<x>,<y>,<z>"));
+        }
+        Ok(())
+    }
+}
+
+fn process_part1(grid: &Grid) -> i32 {
+    let mut surface_area = 0;
+
+    for cube in &grid.cubes {
+        for neighbour in cube.neighbours() {
+            if !grid.cubes.contains(&neighbour) {
+                surface_area += 1;
+            }
+        }
+    }
+
+    surface_area
+}
+
+// --- Part Two ---
+// Something seems off about your calculation. The cooling rate depends on exterior surface area, but your calculation also included the surface area of air pockets trapped in the lava droplet.
+// Instead, consider only cube sides that could be reached by the water and steam as the lava droplet tumbles into the pond. The steam will expand to reach as much as possible, completely displacing any air on the outside of the lava droplet but never expanding diagonally.
+// In the larger example above, exactly one cube of air is trapped within the lava droplet (at 2,2,5), so the exterior surface area of the lava droplet is 58.
+// What is the exterior surface area of your scanned lava droplet?
+
+fn in_range(cube: &Cube, min: &Cube, max: &Cube) -> bool {
+    cube.x >= min.x
+        && cube.x <= max.x
+        && cube.y >= min.y
+        && cube.y <= max.y
+        && cube.z >= min.z
+        && cube.z <= max.z
+}
+
+fn process_part2(grid: &Grid) -> i32 {
+    // Lets first pick a point outside the grid
+    let min_x = grid.cubes.iter().map(|cube| cube.x).min().unwrap();
+    let min_y = grid.cubes.iter().map(|cube| cube.y).min().unwrap();
+    let min_z = grid.cubes.iter().map(|cube| cube.z).min().unwrap();
+
+    let max_x = grid.cubes.iter().map(|cube| cube.x).max().unwrap();
+    let max_y = grid.cubes.iter().map(|cube| cube.y).max().unwrap();
+    let max_z = grid.cubes.iter().map(|cube| cube.z).max().unwrap();
+
+    let min_side = Cube::new(min_x - 1, min_y - 1, min_z - 1);
+    let max_side = Cube::new(max_x + 1, max_y + 1, max_z + 1);
+
+    // Now lets flood fill the grid from the outside
+    let outside = reachable([min_side, max_side], |cube| {
+        cube.neighbours()
+            .filter(|neighbour| {
+                in_range(neighbour, &min_side, &max_side) && !grid.cubes.contains(neighbour)
+            })
+            .collect::<Vec<_>>()
+    });
+
+    // Every side of the droplet that touches the outside is reached by the steam
+    outside
+        .iter()
+        .flat_map(|cube| cube.neighbours())
+        .filter(|neighbour| grid.cubes.contains(neighbour))
+        .count() as i32
+}
+
+#[test]
+fn test_example() {
+    let input = common::parse_input::<Day18>(include_str!("../example.txt")).unwrap();
+    assert_eq!(Day18::part1(&input), Answer::from(64));
+    assert_eq!(Day18::part2(&input), Answer::from(58));
+}
+
+#[test]
+fn test_example_streaming() {
+    let text = include_str!("../example.txt");
+    let answers = common::stream::solve::<Day18>(text.as_bytes()).unwrap();
+    assert_eq!(answers, [Answer::from(64), Answer::from(58)]);
+}
+
+#[test]
+fn test_no_cubes() {
+    let expected = "day18: expected \"<x>,<y>,<z>\"";
+    assert_eq!(part2("").unwrap_err().to_string(), expected);
+    let err = common::stream::solve::<Day18>("".as_bytes()).unwrap_err();
+    assert_eq!(err.to_string(), expected);
+}
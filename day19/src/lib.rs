@@ -0,0 +1,263 @@
+use common::parse::{lines, number};
+use common::search::branch_and_bound;
+use common::{Answer, ParseError, Solution};
+use rayon::prelude::*;
+use regex::Regex;
+use std::str::FromStr;
+use tracing::Span;
+
+pub struct Day19;
+
+common::solution_functions!(Day19);
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Input = Vec<Blueprint>;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        lines(text)
+    }
+
+    fn part1(blueprints: &Self::Input) -> Answer {
+        process_part1(blueprints).into()
+    }
+
+    fn part2(blueprints: &Self::Input) -> Answer {
+        process_part2(blueprints).into()
+    }
+}
+
+// Your scans show that the lava did indeed form obsidian!
+// The wind has changed direction enough to stop sending lava droplets toward you, so you and the elephants exit the cave. As you do, you notice a collection of geodes around the pond. Perhaps you could use the obsidian to create some geode-cracking robots and break them open?
+// To collect the obsidian from the bottom of the pond, you'll need waterproof obsidian-collecting robots. Fortunately, there is an abundant amount of clay nearby that you can use to make them waterproof.
+// In order to harvest the clay, you'll need special-purpose clay-collecting robots. To make any type of robot, you'll need ore, which is also plentiful but in the opposite direction from the clay.
+// Collecting ore requires ore-collecting robots with big drills. Fortunately, you have exactly one ore-collecting robot in your pack that you can use to kickstart the whole operation.
+// Each robot can collect 1 of its resource type per minute. It also takes one minute for the robot factory (also conveniently from your pack) to construct any type of robot, although it consumes the necessary resources available when construction begins.
+// The robot factory has many blueprints (your puzzle input) you can choose from, but once you've configured it with a blueprint, you can't change it. You'll need to work out which blueprint is best.
+// For example:
+//
+// Blueprint 1:
+// Each ore robot costs 4 ore.
+// Each clay robot costs 2 ore.
+// Each obsidian robot costs 3 ore and 14 clay.
+// Each geode robot costs 2 ore and 7 obsidian.
+//
+// Blueprint 2:
+// Each ore robot costs 2 ore.
+// Each clay robot costs 3 ore.
+// Each obsidian robot costs 3 ore and 8 clay.
+// Each geode robot costs 3 ore and 12 obsidian.
+// (Blueprints have been line-wrapped here for legibility. The robot factory's actual assortment of blueprints are provided one blueprint per line.)
+// The elephants are starting to look hungry, so you shouldn't take too long; you need to figure out which blueprint would maximize the number of opened geodes after 24 minutes by figuring out which robots to build and when to build them.
+// Determine the quality level of each blueprint by multiplying that blueprint's ID number with the largest number of geodes that can be opened in 24 minutes using that blueprint. In this example, the first blueprint has ID 1 and can open 9 geodes, so its quality level is 9. The second blueprint has ID 2 and can open 12 geodes, so its quality level is 24. Finally, if you add up the quality levels of all of the blueprints in the list, you get 33.
+// Determine the quality level of each blueprint using the largest number of geodes it could produce in 24 minutes. What do you get if you add up the quality level of all of the blueprints in your list?
+
+#[derive(Debug)]
+pub struct Blueprint {
+    id: i32,
+    ore_ore_cost: i32,
+    clay_ore_cost: i32,
+    obsidian_ore_cost: i32,
+    obsidian_clay_cost: i32,
+    geode_ore_cost: i32,
+    geode_obsidian_cost: i32,
+}
+
+impl FromStr for Blueprint {
+    type Err = ParseError;
+
+    /// Parse a blueprint string
+    /// Example String:
+    /// Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 4 ore. Each obsidian robot costs 2 ore and 16 clay. Each geode robot costs 4 ore and 16 obsidian.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let regex = Regex::new(r"Blueprint (\d+): Each ore robot costs (\d+) ore\. Each clay robot costs (\d+) ore\. Each obsidian robot costs (\d+) ore and (\d+) clay\. Each geode robot costs (\d+) ore and (\d+) obsidian\.").unwrap();
+        let caps = regex.captures(s).ok_or_else(|| {
+            ParseError::expected("Blueprint <id>: Each ore robot costs <n> ore. Each clay robot costs <n> ore. Each obsidian robot costs <n> ore and <n> clay. Each geode robot costs <n> ore and <n> obsidian.")
+        })?;
+        Ok(Blueprint {
+            id: number(s, &caps[1], "<id>")?,
+            ore_ore_cost: number(s, &caps[2], "<n>")?,
+            clay_ore_cost: number(s, &caps[3], "<n>")?,
+            obsidian_ore_cost: number(s, &caps[4], "<n>")?,
+            obsidian_clay_cost: number(s, &caps[5], "<n>")?,
+            geode_ore_cost: number(s, &caps[6], "<n>")?,
+            geode_obsidian_cost: number(s, &caps[7], "<n>")?,
+        })
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Default, Hash)]
+struct State {
+    time: u32,
+    ore: i32,
+    clay: i32,
+    obsidian: i32,
+    geode: i32,
+    ore_robots: i32,
+    clay_robots: i32,
+    obsidian_robots: i32,
+    geode_robots: i32,
+}
+
+impl State {
+    fn earn(&self, minutes: u32) -> Self {
+        let mut new_state = self.clone();
+        new_state.time += minutes;
+        new_state.ore += self.ore_robots * minutes as i32;
+        new_state.clay += self.clay_robots * minutes as i32;
+        new_state.obsidian += self.obsidian_robots * minutes as i32;
+        new_state.geode += self.geode_robots * minutes as i32;
+        new_state
+    }
+
+    /// The geodes opened at the end, when no more robots are built.
+    fn final_geodes(&self, max_time: u32) -> i32 {
+        self.geode + self.geode_robots * (max_time - self.time) as i32
+    }
+
+    /// An upper limit on the geodes opened at the end, if a geode robot could be built every
+    /// minute from now on.
+    fn max_geodes(&self, max_time: u32) -> i32 {
+        let remaining = (max_time - self.time) as i32;
+        self.final_geodes(max_time) + remaining * (remaining - 1) / 2
+    }
+
+    /// The state right after building a robot, waiting until there is enough to pay for it. Robots
+    /// that would only be done at the end are not worth building.
+    fn build_after(&self, wait: Option<u32>, max_time: u32) -> Option<Self> {
+        let new_state = self.earn(wait? + 1);
+        (new_state.time < max_time).then_some(new_state)
+    }
+}
+
+/// The minutes to wait until there is enough of a resource to pay the cost, if it ever arrives.
+fn minutes_until(cost: i32, stock: i32, robots: i32) -> Option<u32> {
+    if stock >= cost {
+        Some(0)
+    } else if robots == 0 {
+        None
+    } else {
+        Some(((cost - stock + robots - 1) / robots) as u32)
+    }
+}
+
+/// The states after building each of the robots next, skipping the minutes spent waiting for
+/// resources. The geode robot comes last, so it is explored first.
+fn next_robots(blueprint: &Blueprint, state: &State, max_time: u32) -> Vec<State> {
+    let max_ore_cost = *[
+        blueprint.ore_ore_cost,
+        blueprint.clay_ore_cost,
+        blueprint.obsidian_ore_cost,
+        blueprint.geode_ore_cost,
+    ]
+    .iter()
+    .max()
+    .unwrap_or(&0);
+
+    let mut states = Vec::new();
+
+    // Robots beyond what can be spent in a single minute are of no use
+    if state.ore_robots < max_ore_cost {
+        let wait = minutes_until(blueprint.ore_ore_cost, state.ore, state.ore_robots);
+        if let Some(mut new_state) = state.build_after(wait, max_time) {
+            new_state.ore -= blueprint.ore_ore_cost;
+            new_state.ore_robots += 1;
+            states.push(new_state);
+        }
+    }
+    if state.clay_robots < blueprint.obsidian_clay_cost {
+        let wait = minutes_until(blueprint.clay_ore_cost, state.ore, state.ore_robots);
+        if let Some(mut new_state) = state.build_after(wait, max_time) {
+            new_state.ore -= blueprint.clay_ore_cost;
+            new_state.clay_robots += 1;
+            states.push(new_state);
+        }
+    }
+    if state.obsidian_robots < blueprint.geode_obsidian_cost {
+        let wait = minutes_until(blueprint.obsidian_ore_cost, state.ore, state.ore_robots)
+            .zip(minutes_until(
+                blueprint.obsidian_clay_cost,
+                state.clay,
+                state.clay_robots,
+            ))
+            .map(|(ore, clay)| ore.max(clay));
+        if let Some(mut new_state) = state.build_after(wait, max_time) {
+            new_state.ore -= blueprint.obsidian_ore_cost;
+            new_state.clay -= blueprint.obsidian_clay_cost;
+            new_state.obsidian_robots += 1;
+            states.push(new_state);
+        }
+    }
+
+    let wait = minutes_until(blueprint.geode_ore_cost, state.ore, state.ore_robots)
+        .zip(minutes_until(
+            blueprint.geode_obsidian_cost,
+            state.obsidian,
+            state.obsidian_robots,
+        ))
+        .map(|(ore, obsidian)| ore.max(obsidian));
+    if let Some(mut new_state) = state.build_after(wait, max_time) {
+        new_state.ore -= blueprint.geode_ore_cost;
+        new_state.obsidian -= blueprint.geode_obsidian_cost;
+        new_state.geode_robots += 1;
+        states.push(new_state);
+    }
+
+    states
+}
+
+/// The blueprints are simulated on the threads of rayon, so the span they run in is passed along
+/// for the span of each blueprint to end up inside it.
+#[tracing::instrument(level = "debug", parent = parent, skip_all, fields(id = blueprint.id))]
+fn simulate_blueprint(parent: &Span, blueprint: &Blueprint, max_time: u32) -> i128 {
+    let start = State {
+        ore_robots: 1,
+        ..Default::default()
+    };
+
+    let best = branch_and_bound(
+        start,
+        |state| next_robots(blueprint, state, max_time),
+        |state| state.final_geodes(max_time) as u32,
+        |state| state.max_geodes(max_time) as u32,
+    );
+    best.value as i128
+}
+
+fn process_part1(blueprints: &[Blueprint]) -> i128 {
+    let span = Span::current();
+    blueprints
+        .par_iter()
+        .map(|b| simulate_blueprint(&span, b, 24) * (b.id as i128))
+        .sum()
+}
+
+// --- Part Two ---
+// While you were choosing the best blueprint, the elephants found some food on their own, so you're not in as much of a hurry; you figure you probably have 32 minutes before the wind changes direction again and you'll need to get out of range of the erupting volcano.
+// Unfortunately, one of the elephants ate most of your blueprint list! Now, only the first three blueprints in your list are intact.
+// In 32 minutes, the largest number of geodes blueprint 1 (from the example above) can open is 56. One way to achieve that is:
+// However, blueprint 2 from the example above is still better; using it, the largest number of geodes you could open in 32 minutes is 62.
+// You no longer have enough blueprints to worry about quality levels. Instead, for each of the first three blueprints, determine the largest number of geodes you could open; then, multiply these three values together.
+// Don't worry about quality levels; instead, just determine the largest number of geodes you could open using each of the first three blueprints. What do you get if you multiply these numbers together?
+
+fn process_part2(blueprints: &[Blueprint]) -> i128 {
+    let span = Span::current();
+    blueprints
+        .par_iter()
+        .take(3)
+        .map(|b| simulate_blueprint(&span, b, 32))
+        .product()
+}
+
+#[test]
+fn test_example_part1() {
+    let input = common::parse_input::<Day19>(include_str!("../example.txt")).unwrap();
+    assert_eq!(Day19::part1(&input), Answer::from(33));
+}
+
+#[test]
+fn test_example_part2() {
+    let input = common::parse_input::<Day19>(include_str!("../example.txt")).unwrap();
+    assert_eq!(Day19::part2(&input), Answer::from(3472));
+}
@@ -0,0 +1,297 @@
+//! Working out what the second column of the strategy guide means, without the elf around to ask.
+//!
+//! Every way of reading its letters as distinct shapes, or as distinct outcomes, is scored. An
+//! author who knows the opponent's moves writes the guide with them in mind, so under the reading
+//! they meant, the shapes you play follow the opponent's: the reading most plausibly meant is the
+//! one under which the opponent's shape tells the most about yours. Readings that tell as much as
+//! each other, like outcomes that are only rotated, which just renames the shapes you play, are
+//! told apart by whether the letters follow the order of the rules, like A, B and C follow the
+//! order of the shapes, and then by their score.
+use crate::game::{Game, Outcome, Shape};
+use crate::{next_part, next_shape};
+use common::ParseError;
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+
+/// What the letters of the second column stand for, in the order of [`Analysis::letters`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Meaning {
+    Shapes(Vec<Shape>),
+    Outcomes(Vec<Outcome>),
+}
+
+/// How the guide plays out when read a certain way.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reading {
+    pub meaning: Meaning,
+    pub score: u64,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    /// How much the opponent's shape tells about yours over the rounds, as the mutual information
+    /// between them in bits.
+    pub information: f64,
+    /// Whether the letters stand for the shapes or outcomes in the order the rules declare them.
+    pub in_order: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Analysis {
+    /// The letters of the second column, in alphabetical order.
+    pub letters: Vec<String>,
+    /// Every reading, from the highest score to the lowest.
+    pub readings: Vec<Reading>,
+    /// The index of the reading the author most plausibly meant, if there is any reading at all.
+    pub plausible: Option<usize>,
+}
+
+impl Analysis {
+    /// The reading as the letters with what they stand for, like `"X = lose, Y = draw, Z = win"`.
+    pub fn describe(&self, game: &Game, reading: &Reading) -> String {
+        let meanings: Vec<&str> = match &reading.meaning {
+            Meaning::Shapes(shapes) => shapes.iter().map(|&shape| game.name(shape)).collect(),
+            Meaning::Outcomes(outcomes) => outcomes.iter().map(|outcome| outcome.name()).collect(),
+        };
+        self.letters
+            .iter()
+            .zip(meanings)
+            .map(|(letter, meaning)| format!("{} = {}", letter, meaning))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// Reads the guide every way its second column could be meant in the game. Letters that don't
+/// fit the game are fine in the second column, the first one has to be the opponent's shapes.
+pub fn analyse(game: &Game, text: &str) -> Result<Analysis, ParseError> {
+    // How often every opponent's shape comes with every letter, which is all a reading needs
+    let mut pairs = BTreeMap::new();
+    for (index, line) in text.lines().enumerate() {
+        let mut parts = line.split_whitespace();
+        let pair = next_shape(line, &mut parts, game)
+            .and_then(|elf| Ok((elf, next_part(line, &mut parts)?)))
+            .map_err(|err| err.offset_line(index))?;
+        *pairs.entry(pair).or_insert(0) += 1;
+    }
+    let mut letters = pairs.keys().map(|&(_, letter)| letter).collect::<Vec<_>>();
+    letters.sort_unstable();
+    letters.dedup();
+
+    let shapes = game.shapes().collect::<Vec<_>>();
+    let meanings = arrangements(shapes.len(), letters.len())
+        .into_iter()
+        .map(|chosen| Meaning::Shapes(chosen.iter().map(|&i| shapes[i]).collect()))
+        .chain(
+            arrangements(Outcome::ALL.len(), letters.len())
+                .into_iter()
+                .map(|chosen| Meaning::Outcomes(chosen.iter().map(|&i| Outcome::ALL[i]).collect())),
+        );
+
+    let mut readings = meanings
+        .map(|meaning| read(game, &pairs, &letters, meaning))
+        .collect::<Vec<_>>();
+    readings.sort_by_key(|reading| Reverse(reading.score));
+    let plausible = (0..readings.len()).max_by(|&a, &b| {
+        let (first, second) = (&readings[a], &readings[b]);
+        // Of readings that are just as plausible, the one ranked first is kept
+        first
+            .information
+            .total_cmp(&second.information)
+            .then(first.in_order.cmp(&second.in_order))
+            .then(first.score.cmp(&second.score))
+            .then(b.cmp(&a))
+    });
+
+    Ok(Analysis {
+        letters: letters.into_iter().map(str::to_string).collect(),
+        readings,
+        plausible,
+    })
+}
+
+fn read(
+    game: &Game,
+    pairs: &BTreeMap<(Shape, &str), usize>,
+    letters: &[&str],
+    meaning: Meaning,
+) -> Reading {
+    let mut reading = Reading {
+        meaning,
+        score: 0,
+        wins: 0,
+        draws: 0,
+        losses: 0,
+        information: 0.0,
+        in_order: false,
+    };
+    // How often every shape is played by the opponent, by you, and by both against each other
+    let mut elves = BTreeMap::<Shape, usize>::new();
+    let mut mine = BTreeMap::<Shape, usize>::new();
+    let mut both = BTreeMap::<(Shape, Shape), usize>::new();
+    for (&(elf, letter), &count) in pairs {
+        let index = letters.binary_search(&letter).unwrap();
+        let (me, outcome) = match &reading.meaning {
+            Meaning::Shapes(shapes) => (shapes[index], game.outcome(elf, shapes[index])),
+            Meaning::Outcomes(outcomes) => (game.response(elf, outcomes[index]), outcomes[index]),
+        };
+        reading.score +=
+            (u64::from(game.score(me)) + u64::from(game.outcome_score(outcome))) * count as u64;
+        match outcome {
+            Outcome::Lose => reading.losses += count,
+            Outcome::Draw => reading.draws += count,
+            Outcome::Win => reading.wins += count,
+        }
+        *elves.entry(elf).or_default() += count;
+        *mine.entry(me).or_default() += count;
+        *both.entry((elf, me)).or_default() += count;
+    }
+    let rounds = reading.wins + reading.draws + reading.losses;
+    if rounds > 0 {
+        reading.information = (weighted_log(&[rounds]) + weighted_log(both.values())
+            - weighted_log(elves.values())
+            - weighted_log(mine.values()))
+            / rounds as f64;
+    }
+    reading.in_order = match &reading.meaning {
+        Meaning::Shapes(shapes) => shapes.windows(2).all(|pair| pair[0] < pair[1]),
+        Meaning::Outcomes(outcomes) => outcomes.windows(2).all(|pair| pair[0] < pair[1]),
+    };
+    reading
+}
+
+/// The sum of `count * log2(count)` over the counts, which is what the entropy of how often
+/// things happen comes down to. The counts are added up from the smallest, so readings that only
+/// rename the shapes come out exactly the same.
+fn weighted_log<'a>(counts: impl IntoIterator<Item = &'a usize>) -> f64 {
+    let mut counts = counts.into_iter().copied().collect::<Vec<_>>();
+    counts.sort_unstable();
+    counts
+        .into_iter()
+        .map(|count| count as f64 * (count as f64).log2())
+        .sum()
+}
+
+/// Every way of picking `k` of `n` items in order, without picking an item twice.
+fn arrangements(n: usize, k: usize) -> Vec<Vec<usize>> {
+    let mut all = vec![Vec::new()];
+    for _ in 0..k {
+        all = all
+            .iter()
+            .flat_map(|chosen: &Vec<usize>| {
+                (0..n)
+                    .filter(|i| !chosen.contains(i))
+                    .map(|i| [chosen.as_slice(), &[i]].concat())
+            })
+            .collect();
+    }
+    all
+}
+
+#[test]
+fn test_analyse_example() {
+    let game = Game::rock_paper_scissors();
+    let analysis = analyse(game, include_str!("../example.txt")).unwrap();
+
+    assert_eq!(analysis.letters, ["X", "Y", "Z"]);
+    // Six ways to read the letters as shapes, and six as outcomes
+    assert_eq!(analysis.readings.len(), 12);
+    assert!(analysis
+        .readings
+        .windows(2)
+        .all(|pair| pair[0].score >= pair[1].score));
+
+    let score_of = |description: &str| {
+        let reading = analysis
+            .readings
+            .iter()
+            .find(|reading| analysis.describe(game, reading) == description)
+            .unwrap();
+        reading.score
+    };
+    assert_eq!(score_of("X = Rock, Y = Paper, Z = Scissors"), 15);
+    assert_eq!(score_of("X = lose, Y = draw, Z = win"), 12);
+
+    // Three rounds are too few to tell most readings apart, whoever's moves they follow, so the
+    // order of the rules decides rather than the reading that wins every round
+    let plausible = &analysis.readings[analysis.plausible.unwrap()];
+    assert_eq!(
+        analysis.describe(game, plausible),
+        "X = Rock, Y = Paper, Z = Scissors"
+    );
+    assert_eq!(analysis.readings[0].wins, 3);
+    assert!(analysis.readings[0].information <= plausible.information);
+}
+
+#[test]
+fn test_most_plausible_reading() {
+    let game = Game::rock_paper_scissors();
+    let analysis = analyse(game, include_str!("../input.txt")).unwrap();
+
+    // A whole guide reads as the outcomes the puzzle says it meant, although reading it as
+    // shapes scores more
+    let plausible = &analysis.readings[analysis.plausible.unwrap()];
+    assert_eq!(
+        analysis.describe(game, plausible),
+        "X = lose, Y = draw, Z = win"
+    );
+    assert!(plausible.score < analysis.readings[0].score);
+    for reading in &analysis.readings {
+        let rotated = match &reading.meaning {
+            Meaning::Shapes(_) => false,
+            Meaning::Outcomes(outcomes) => {
+                (0..3).any(|offset| (0..3).all(|i| outcomes[i] == Outcome::ALL[(i + offset) % 3]))
+            }
+        };
+        if rotated {
+            assert_eq!(reading.information, plausible.information);
+        } else {
+            assert!(reading.information < plausible.information);
+        }
+    }
+
+    // The same second column against moves that the outcomes would have you answer with Rock
+    // every time reads as shapes
+    let text = include_str!("../input.txt")
+        .lines()
+        .map(|line| match &line[2..] {
+            "X" => "B X",
+            "Y" => "A Y",
+            _ => "C Z",
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let analysis = analyse(game, &text).unwrap();
+    let plausible = &analysis.readings[analysis.plausible.unwrap()];
+    assert_eq!(
+        analysis.describe(game, plausible),
+        "X = Rock, Y = Paper, Z = Scissors"
+    );
+
+    let err = analyse(game, "A X\nQ Y").unwrap_err();
+    assert_eq!(err.line(), Some(2));
+}
+
+#[test]
+fn test_scores_past_u32() {
+    let rules = include_str!("../rules/rock-paper-scissors.txt");
+    let game = rules
+        .replace("shape Rock 1 A X", "shape Rock 4000000000 A X")
+        .parse::<Game>()
+        .unwrap();
+    let analysis = analyse(&game, "A X\nB X").unwrap();
+
+    let reading = analysis
+        .readings
+        .iter()
+        .find(|reading| analysis.describe(&game, reading) == "X = Rock")
+        .unwrap();
+    assert_eq!(reading.score, 8000000003);
+}
+
+#[test]
+fn test_arrangements() {
+    assert_eq!(arrangements(3, 2).len(), 6);
+    assert_eq!(arrangements(5, 3).len(), 60);
+    assert_eq!(arrangements(2, 3), Vec::<Vec<usize>>::new());
+    assert_eq!(arrangements(3, 0), [Vec::<usize>::new()]);
+}
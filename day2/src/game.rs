@@ -0,0 +1,355 @@
+//! The rules of games like rock paper scissors, read from a rules file so the strategy guide can be
+//! played in games with other shapes, like rock paper scissors lizard Spock.
+//!
+//! A rules file has a rule per line, and anything after a `#` is a comment:
+//!
+//! ```text
+//! shape <name> <score> <letters>...
+//! outcome <lose|draw|win> <score> <letters>...
+//! <shape> beats <shape>
+//! ```
+//!
+//! The letters are how the strategy guide writes a shape or an outcome. Every two shapes need a
+//! rule on which of them wins. There has to be an odd number of shapes, at least three, and every
+//! shape has to beat as many shapes as it loses to, so no shape is better than another and the
+//! guide can always ask for any outcome.
+use common::parse::number;
+use common::ParseError;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+/// A shape of a [`Game`], only meaningful together with the game it came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Shape(usize);
+
+/// How a round ends for the player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Outcome::Lose => "lose",
+            Outcome::Draw => "draw",
+            Outcome::Win => "win",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Game {
+    /// The name and score of every shape, in the order they were declared.
+    shapes: Vec<(String, u32)>,
+    /// Whether the shape of the row beats the shape of the column.
+    beats: Vec<Vec<bool>>,
+    shape_letters: Vec<(String, Shape)>,
+    /// The score of every outcome, in the order of [`Outcome::ALL`]. All of them are there once
+    /// the rules have been read.
+    outcome_scores: [Option<u32>; 3],
+    outcome_letters: Vec<(String, Outcome)>,
+}
+
+impl Game {
+    /// The game the puzzle is about.
+    pub fn rock_paper_scissors() -> &'static Game {
+        static GAME: OnceLock<Game> = OnceLock::new();
+        GAME.get_or_init(|| {
+            include_str!("../rules/rock-paper-scissors.txt")
+                .parse()
+                .expect("the rules of rock paper scissors are valid")
+        })
+    }
+
+    pub fn shapes(&self) -> impl Iterator<Item = Shape> {
+        (0..self.shapes.len()).map(Shape)
+    }
+
+    pub fn name(&self, shape: Shape) -> &str {
+        &self.shapes[shape.0].0
+    }
+
+    pub fn score(&self, shape: Shape) -> u32 {
+        self.shapes[shape.0].1
+    }
+
+    pub fn outcome_score(&self, outcome: Outcome) -> u32 {
+        self.outcome_scores[outcome as usize].expect("every outcome has a score")
+    }
+
+    /// The shape the letter stands for, if any.
+    pub fn shape(&self, letter: &str) -> Option<Shape> {
+        lookup(&self.shape_letters, letter)
+    }
+
+    /// The outcome the letter stands for, if any.
+    pub fn outcome_of_letter(&self, letter: &str) -> Option<Outcome> {
+        lookup(&self.outcome_letters, letter)
+    }
+
+    /// The letters that stand for a shape, like `"A, B or C"`.
+    pub fn describe_shape_letters(&self) -> String {
+        describe(&self.shape_letters)
+    }
+
+    /// The letters that stand for an outcome, like `"X, Y or Z"`.
+    pub fn describe_outcome_letters(&self) -> String {
+        describe(&self.outcome_letters)
+    }
+
+    /// How the round ends for the player playing `me` against `elf`.
+    pub fn outcome(&self, elf: Shape, me: Shape) -> Outcome {
+        if elf == me {
+            Outcome::Draw
+        } else if self.beats[elf.0][me.0] {
+            Outcome::Lose
+        } else {
+            Outcome::Win
+        }
+    }
+
+    /// The shape to play against `elf` for the round to end in `outcome`. When several shapes do
+    /// that, like in games with more than three shapes, the one scoring the most is played, and of
+    /// those the one declared first.
+    pub fn response(&self, elf: Shape, outcome: Outcome) -> Shape {
+        self.shapes()
+            .filter(|&me| self.outcome(elf, me) == outcome)
+            .min_by_key(|&me| std::cmp::Reverse(self.score(me)))
+            .expect("every shape beats some shapes and loses to others")
+    }
+
+    fn read_rule(&mut self, line: &str) -> Result<(), ParseError> {
+        let content = line.split('#').next().unwrap_or_default();
+        let words = content.split_whitespace().collect::<Vec<_>>();
+        match words[..] {
+            [] => Ok(()),
+            ["shape", name, score, ref letters @ ..] => {
+                if self.shapes.iter().any(|(other, _)| other == name) {
+                    return Err(ParseError::expected("a shape not declared above").at(line, name));
+                }
+                let shape = Shape(self.shapes.len());
+                self.shapes
+                    .push((name.to_string(), number(line, score, "<score>")?));
+                for row in &mut self.beats {
+                    row.push(false);
+                }
+                self.beats.push(vec![false; self.shapes.len()]);
+                add_letters(&mut self.shape_letters, line, letters, shape)
+            }
+            ["outcome", name, score, ref letters @ ..] => {
+                let outcome = Outcome::ALL
+                    .into_iter()
+                    .find(|outcome| outcome.name() == name)
+                    .ok_or_else(|| ParseError::expected("lose, draw or win").at(line, name))?;
+                if self.outcome_scores[outcome as usize].is_some() {
+                    return Err(
+                        ParseError::expected("an outcome not declared above").at(line, name)
+                    );
+                }
+                self.outcome_scores[outcome as usize] = Some(number(line, score, "<score>")?);
+                add_letters(&mut self.outcome_letters, line, letters, outcome)
+            }
+            [winner, "beats", loser] => {
+                let winner = self.declared(line, winner)?;
+                let loser_shape = self.declared(line, loser)?;
+                if winner == loser_shape {
+                    return Err(ParseError::expected(format!(
+                        "a shape other than {}",
+                        self.name(winner)
+                    ))
+                    .at(line, loser));
+                }
+                if self.beats[loser_shape.0][winner.0] {
+                    return Err(ParseError::expected(format!(
+                        "a shape that doesn't beat {}",
+                        self.name(winner)
+                    ))
+                    .at(line, loser));
+                }
+                self.beats[winner.0][loser_shape.0] = true;
+                Ok(())
+            }
+            _ => Err(
+                ParseError::expected("shape, outcome or <shape> beats <shape>")
+                    .at(line, content.trim_start()),
+            ),
+        }
+    }
+
+    fn declared(&self, line: &str, name: &str) -> Result<Shape, ParseError> {
+        self.shapes
+            .iter()
+            .position(|(other, _)| other == name)
+            .map(Shape)
+            .ok_or_else(|| ParseError::expected("a shape declared above").at(line, name))
+    }
+
+    /// Checks that the rules say how every round ends, whatever is played, and that no shape
+    /// wins more often than another.
+    fn check(&self) -> Result<(), ParseError> {
+        for outcome in Outcome::ALL {
+            if self.outcome_scores[outcome as usize].is_none() {
+                return Err(ParseError::expected(format!(
+                    "outcome {} <score> <letters>...",
+                    outcome.name()
+                )));
+            }
+        }
+        for a in self.shapes() {
+            for b in self.shapes().filter(|&b| b > a) {
+                if !self.beats[a.0][b.0] && !self.beats[b.0][a.0] {
+                    return Err(ParseError::expected(format!(
+                        "{} beats {} or {} beats {}",
+                        self.name(a),
+                        self.name(b),
+                        self.name(b),
+                        self.name(a)
+                    )));
+                }
+            }
+        }
+        if self.shapes.len() < 3 || self.shapes.len().is_multiple_of(2) {
+            return Err(ParseError::expected("an odd number of shapes, at least 3"));
+        }
+        // With every two shapes related, a shape beating half of the others loses to the rest
+        let half = (self.shapes.len() - 1) / 2;
+        for a in self.shapes() {
+            if self.shapes().filter(|b| self.beats[a.0][b.0]).count() != half {
+                return Err(ParseError::expected(format!(
+                    "{} to beat {} of the other shapes",
+                    self.name(a),
+                    half
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut game = Game::default();
+        for (index, line) in s.lines().enumerate() {
+            game.read_rule(line).map_err(|err| err.offset_line(index))?;
+        }
+        game.check()?;
+        Ok(game)
+    }
+}
+
+fn add_letters<T: Copy>(
+    letters: &mut Vec<(String, T)>,
+    line: &str,
+    new: &[&str],
+    value: T,
+) -> Result<(), ParseError> {
+    for &letter in new {
+        if letters.iter().any(|(other, _)| other == letter) {
+            return Err(ParseError::expected("a letter not taken above").at(line, letter));
+        }
+        letters.push((letter.to_string(), value));
+    }
+    Ok(())
+}
+
+fn lookup<T: Copy>(letters: &[(String, T)], letter: &str) -> Option<T> {
+    letters
+        .iter()
+        .find(|(other, _)| other == letter)
+        .map(|&(_, value)| value)
+}
+
+fn describe<T>(letters: &[(String, T)]) -> String {
+    let mut letters = letters
+        .iter()
+        .map(|(letter, _)| letter.as_str())
+        .collect::<Vec<_>>();
+    letters.sort_unstable();
+    match letters.split_last() {
+        Some((last, [])) => last.to_string(),
+        Some((last, rest)) => format!("{} or {}", rest.join(", "), last),
+        None => String::new(),
+    }
+}
+
+#[test]
+fn test_rock_paper_scissors_lizard_spock() {
+    let game = include_str!("../rules/rock-paper-scissors-lizard-spock.txt")
+        .parse::<Game>()
+        .unwrap();
+    let shape = |letter| game.shape(letter).unwrap();
+    let (rock, paper, lizard, spock) = (shape("A"), shape("B"), shape("D"), shape("E"));
+
+    assert_eq!(game.shapes().count(), 5);
+    assert_eq!(game.name(spock), "Spock");
+    assert_eq!(game.outcome(rock, lizard), Outcome::Lose);
+    assert_eq!(game.outcome(rock, spock), Outcome::Win);
+    assert_eq!(game.outcome(lizard, lizard), Outcome::Draw);
+
+    // Both Paper and Spock beat Rock, Spock scores more
+    assert_eq!(game.response(rock, Outcome::Win), spock);
+    assert_eq!(game.response(spock, Outcome::Win), lizard);
+    assert_eq!(game.response(paper, Outcome::Lose), spock);
+    assert_eq!(game.response(paper, Outcome::Draw), paper);
+    assert_eq!(game.describe_outcome_letters(), "X, Y or Z");
+}
+
+#[test]
+fn test_invalid_rules() {
+    let error = |rules: &str| rules.parse::<Game>().unwrap_err().to_string();
+    let rules = include_str!("../rules/rock-paper-scissors.txt");
+
+    assert_eq!(
+        error(&rules.replace("Paper beats Rock", "Rock beats Paper")),
+        "expected \"Rock to beat 1 of the other shapes\""
+    );
+    assert_eq!(
+        error(&format!("{}Paper beats Scissors", rules)),
+        "line 18 column 13: expected \"a shape that doesn't beat Paper\""
+    );
+    assert_eq!(
+        error(&rules.replace("Scissors beats Paper", "Scissors beats Stone")),
+        "line 16 column 16: expected \"a shape declared above\""
+    );
+    assert_eq!(
+        error(&rules.replace("outcome draw 3 Y", "outcome tie 3 Y")),
+        "line 11 column 9: expected \"lose, draw or win\""
+    );
+    assert_eq!(
+        error(&rules.replace("shape Paper 2 B Y", "shape Paper 2 B X")),
+        "line 6 column 17: expected \"a letter not taken above\""
+    );
+    assert_eq!(
+        error(&rules.replace("Rock beats Scissors", "")),
+        "expected \"Rock beats Scissors or Scissors beats Rock\""
+    );
+}
+
+#[test]
+fn test_unbalanced_rules() {
+    let error = |rules: &str| rules.parse::<Game>().unwrap_err().to_string();
+    let rules = include_str!("../rules/rock-paper-scissors-lizard-spock.txt");
+
+    // Every shape still beats one and loses to one, but Rock beats three
+    assert_eq!(
+        error(&rules.replace("Spock beats Rock", "Rock beats Spock")),
+        "expected \"Rock to beat 2 of the other shapes\""
+    );
+    // Without Spock, no shape can beat as many shapes as it loses to
+    let without_spock = rules
+        .lines()
+        .filter(|line| !line.contains("Spock"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    assert_eq!(
+        error(&without_spock),
+        "expected \"an odd number of shapes, at least 3\""
+    );
+}
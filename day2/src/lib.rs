@@ -0,0 +1,218 @@
+use common::parse::lines_with;
+use common::stream::Streaming;
+use common::{Answer, ParseError, Solution};
+use game::{Game, Shape};
+
+pub mod analysis;
+pub mod game;
+
+pub struct Day2;
+
+common::solution_functions!(Day2);
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Guide;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        Guide::read(text, Game::rock_paper_scissors())
+    }
+
+    fn part1(guide: &Self::Input) -> Answer {
+        process_part1(&guide.rounds, Game::rock_paper_scissors()).into()
+    }
+
+    fn part2(guide: &Self::Input) -> Answer {
+        process_part2(&guide.decoded_rounds, Game::rock_paper_scissors()).into()
+    }
+}
+
+/// The strategy guide, read the way each part interprets the second column.
+pub struct Guide {
+    rounds: Vec<Round>,
+    decoded_rounds: Vec<Round>,
+}
+
+impl Guide {
+    fn read(text: &str, game: &Game) -> Result<Guide, ParseError> {
+        Ok(Guide {
+            rounds: process_rounds1(text, game)?,
+            decoded_rounds: process_rounds2(text, game)?,
+        })
+    }
+}
+
+impl Streaming for Day2 {
+    /// The total score of both readings of the guide so far.
+    type State = (u64, u64);
+
+    fn feed(scores: &mut Self::State, line: &str) -> Result<(), ParseError> {
+        let game = Game::rock_paper_scissors();
+        scores.0 += parse_round(line, game)?.total_score(game);
+        scores.1 += decode_round(line, game)?.total_score(game);
+        Ok(())
+    }
+
+    fn finish(scores: Self::State) -> Result<[Answer; 2], ParseError> {
+        Ok([scores.0.into(), scores.1.into()])
+    }
+}
+
+/// The total score of both readings of the strategy guide when playing another game than rock
+/// paper scissors.
+pub fn play(game: &Game, text: &str) -> Result<[u64; 2], ParseError> {
+    let guide = Guide::read(text, game).map_err(|err| err.in_day(Day2::DAY))?;
+    Ok([
+        process_part1(&guide.rounds, game),
+        process_part2(&guide.decoded_rounds, game),
+    ])
+}
+
+// PART 1
+//
+// The Elves begin to set up camp on the beach. To decide whose tent gets to be closest to the snack storage, a giant Rock Paper Scissors tournament is already in progress.
+//
+// Rock Paper Scissors is a game between two players. Each game contains many rounds; in each round, the players each simultaneously choose one of Rock, Paper, or Scissors using a hand shape. Then, a winner for that round is selected: Rock defeats Scissors, Scissors defeats Paper, and Paper defeats Rock. If both players choose the same shape, the round instead ends in a draw.
+//
+// Appreciative of your help yesterday, one Elf gives you an encrypted strategy guide (your puzzle input) that they say will be sure to help you win. "The first column is what your opponent is going to play: A for Rock, B for Paper, and C for Scissors. The second column--" Suddenly, the Elf is called away to help with someone's tent.
+//
+// The second column, you reason, must be what you should play in response: X for Rock, Y for Paper, and Z for Scissors. Winning every time would be suspicious, so the responses must have been carefully chosen.
+//
+// The winner of the whole tournament is the player with the highest score. Your total score is the sum of your scores for each round. The score for a single round is the score for the shape you selected (1 for Rock, 2 for Paper, and 3 for Scissors) plus the score for the outcome of the round (0 if you lost, 3 if the round was a draw, and 6 if you won).
+//
+// Since you can't be sure if the Elf is trying to help you or trick you, you should calculate the score you would get if you were to follow the strategy guide.
+//
+// For example, suppose you were given the following strategy guide:
+//
+// A Y
+// B X
+// C Z
+// This strategy guide predicts and recommends the following:
+//
+// In the first round, your opponent will choose Rock (A), and you should choose Paper (Y). This ends in a win for you with a score of 8 (2 because you chose Paper + 6 because you won).
+// In the second round, your opponent will choose Paper (B), and you should choose Rock (X). This ends in a loss for you with a score of 1 (1 + 0).
+// The third round is a draw with both players choosing Scissors, giving you a score of 3 + 3 = 6.
+// In this example, if you were to follow the strategy guide, you would get a total score of 15 (8 + 1 + 6).
+//
+// What would your total score be if everything goes exactly according to your strategy guide?
+
+struct Round {
+    elf: Shape,
+    me: Shape,
+}
+
+impl Round {
+    fn new(elf: Shape, me: Shape) -> Round {
+        Round { elf, me }
+    }
+    fn round_score(&self, game: &Game) -> u32 {
+        game.outcome_score(game.outcome(self.elf, self.me))
+    }
+
+    fn total_score(&self, game: &Game) -> u64 {
+        u64::from(game.score(self.me)) + u64::from(self.round_score(game))
+    }
+}
+
+/// Reads the shape in the next column of the line.
+fn next_shape<'a>(
+    line: &'a str,
+    parts: &mut impl Iterator<Item = &'a str>,
+    game: &Game,
+) -> Result<Shape, ParseError> {
+    let part = next_part(line, parts)?;
+    game.shape(part)
+        .ok_or_else(|| ParseError::expected(game.describe_shape_letters()).at(line, part))
+}
+
+/// Reads the next column of the line.
+fn next_part<'a>(
+    line: &'a str,
+    parts: &mut impl Iterator<Item = &'a str>,
+) -> Result<&'a str, ParseError> {
+    parts
+        .next()
+        .ok_or_else(|| ParseError::expected("<shape>").at(line, &line[line.len()..]))
+}
+
+fn parse_round(line: &str, game: &Game) -> Result<Round, ParseError> {
+    let mut parts = line.split_whitespace();
+    let elf = next_shape(line, &mut parts, game)?;
+    let me = next_shape(line, &mut parts, game)?;
+    Ok(Round::new(elf, me))
+}
+
+fn process_part1(rounds: &[Round], game: &Game) -> u64 {
+    rounds.iter().map(|r| r.total_score(game)).sum()
+}
+
+fn process_rounds1(text: &str, game: &Game) -> Result<Vec<Round>, ParseError> {
+    lines_with(text, |line| parse_round(line, game))
+}
+
+// --- Part Two ---
+//
+// The Elf finishes helping with the tent and sneaks back over to you. "Anyway, the second column says how the round needs to end: X means you need to lose, Y means you need to end the round in a draw, and Z means you need to win. Good luck!"
+//
+// The total score is still calculated in the same way, but now you need to figure out what shape to choose so the round ends as indicated. The example above now goes like this:
+//
+// In the first round, your opponent will choose Rock (A), and you need the round to end in a draw (Y), so you also choose Rock. This gives you a score of 1 + 3 = 4.
+// In the second round, your opponent will choose Paper (B), and you choose Rock so you lose (X) with a score of 1 + 0 = 1.
+// In the third round, you will defeat your opponent's Scissors with Rock for a score of 1 + 6 = 7.
+// Now that you're correctly decrypting the ultra top secret strategy guide, you would get a total score of 12.
+
+fn process_part2(rounds: &[Round], game: &Game) -> u64 {
+    rounds.iter().map(|r| r.total_score(game)).sum()
+}
+
+fn process_rounds2(text: &str, game: &Game) -> Result<Vec<Round>, ParseError> {
+    lines_with(text, |line| decode_round(line, game))
+}
+
+fn decode_round(text: &str, game: &Game) -> Result<Round, ParseError> {
+    let mut parts = text.split_whitespace();
+    let elf = next_shape(text, &mut parts, game)?;
+    let outcome = next_part(text, &mut parts)?;
+
+    // The second part says how the round needs to end, so I need to play the shape that loses to,
+    // draws with or beats the elf's shape
+    let outcome = game
+        .outcome_of_letter(outcome)
+        .ok_or_else(|| ParseError::expected(game.describe_outcome_letters()).at(text, outcome))?;
+
+    Ok(Round::new(elf, game.response(elf, outcome)))
+}
+
+#[test]
+fn test_example() {
+    let input = common::parse_input::<Day2>(include_str!("../example.txt")).unwrap();
+    assert_eq!(Day2::part1(&input), Answer::from(15));
+    assert_eq!(Day2::part2(&input), Answer::from(12));
+}
+
+#[test]
+fn test_example_streaming() {
+    let text = include_str!("../example.txt");
+    let answers = common::stream::solve::<Day2>(text.as_bytes()).unwrap();
+    assert_eq!(answers, [Answer::from(15), Answer::from(12)]);
+}
+
+#[test]
+fn test_example_in_other_games() {
+    let text = include_str!("../example.txt");
+    let game = include_str!("../rules/rock-paper-scissors-lizard-spock.txt")
+        .parse::<Game>()
+        .unwrap();
+    // A Y is Lizard losing to Rock, or drawing with Rock once decoded
+    // B X is Scissors beating Paper, or losing to it with Spock, as it scores more than Rock
+    // C Z is Spock beating Scissors, or beating it with Spock again
+    assert_eq!(play(&game, text), Ok([4 + 9 + 11, 4 + 5 + 11]));
+
+    let err = play(&game, "A Q").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "day2 line 1 column 3: expected \"A, B, C, D, E, V, W, X, Y or Z\""
+    );
+    assert_eq!(play(Game::rock_paper_scissors(), text), Ok([15, 12]));
+}
@@ -0,0 +1,120 @@
+use common::parse::{lines_with, number};
+use common::stream::Streaming;
+use common::{Answer, ParseError, Solution};
+use std::collections::VecDeque;
+
+pub struct Day20;
+
+common::solution_functions!(Day20);
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input = Vec<i64>;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        parse_input(text)
+    }
+
+    fn part1(numbers: &Self::Input) -> Answer {
+        process_part1(numbers).into()
+    }
+
+    fn part2(numbers: &Self::Input) -> Answer {
+        process_part2(numbers).into()
+    }
+}
+
+impl Streaming for Day20 {
+    // Mixing moves every number around, so all of them are kept
+    type State = Vec<i64>;
+
+    fn feed(numbers: &mut Self::State, line: &str) -> Result<(), ParseError> {
+        numbers.push(number(line, line, "<n>")?);
+        Ok(())
+    }
+
+    fn finish(numbers: Self::State) -> Result<[Answer; 2], ParseError> {
+        check_numbers(&numbers)?;
+        Ok([Day20::part1(&numbers), Day20::part2(&numbers)])
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<i64>, ParseError> {
+    let numbers: Vec<i64> = lines_with(input, |line| number(line, line, "<n>"))?;
+    check_numbers(&numbers)?;
+    Ok(numbers)
+}
+
+fn check_numbers(numbers: &[i64]) -> Result<(), ParseError> {
+    // A number is moved around the others, so there have to be some
+    if numbers.len() == 1 {
+        return Err(ParseError::expected("another <n>").offset_line(1));
+    }
+    // The grove coordinates are counted from the 0
+    if !numbers.contains(&0) {
+        return Err(ParseError::expected("0 in the file"));
+    }
+    Ok(())
+}
+
+fn solve(values: &[i64], key: i64, rounds: usize) -> i64 {
+    let mut values = values
+        .iter()
+        .map(|v| *v * key)
+        .enumerate()
+        .collect::<VecDeque<_>>();
+
+    for _ in 0..rounds {
+        for i in 0..values.len() {
+            let current_index = values.iter().position(|(index, _)| *index == i).unwrap();
+
+            values.rotate_left(current_index);
+            let (index, value) = values.pop_front().unwrap();
+            let d = value.rem_euclid(values.len() as i64) as usize;
+            values.rotate_left(d);
+            values.push_front((index, value));
+        }
+    }
+
+    let zero_index = values.iter().position(|(_, value)| *value == 0).unwrap();
+
+    let position_of_interest = [1000, 2000, 3000];
+
+    position_of_interest
+        .iter()
+        .map(|pos| values[(zero_index + pos) % values.len()].1)
+        .sum()
+}
+
+fn process_part1(numbers: &[i64]) -> i64 {
+    solve(numbers, 1, 1)
+}
+
+fn process_part2(numbers: &[i64]) -> i64 {
+    solve(numbers, 811_589_153, 10)
+}
+
+#[test]
+fn test_example() {
+    let input = common::parse_input::<Day20>(include_str!("../example.txt")).unwrap();
+    assert_eq!(Day20::part1(&input), Answer::from(3));
+    assert_eq!(Day20::part2(&input), Answer::from(1623178306_i64));
+}
+
+#[test]
+fn test_example_streaming() {
+    let text = include_str!("../example.txt");
+    let answers = common::stream::solve::<Day20>(text.as_bytes()).unwrap();
+    assert_eq!(answers, [Answer::from(3), Answer::from(1623178306_i64)]);
+}
+
+#[test]
+fn test_invalid_numbers() {
+    assert_eq!(
+        part1("0").unwrap_err().to_string(),
+        "day20 line 2: expected \"another <n>\""
+    );
+    let err = common::stream::solve::<Day20>("0\n".as_bytes()).unwrap_err();
+    assert_eq!(err.to_string(), "day20 line 2: expected \"another <n>\"");
+}
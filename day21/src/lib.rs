@@ -0,0 +1,386 @@
+use common::parse::lines_with;
+use common::{Answer, ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+
+use crate::Math::{Human, Number};
+use crate::Operation::{LeftDivide, LeftSubtract};
+use std::str::FromStr;
+
+pub struct Day21;
+
+common::solution_functions!(Day21);
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Input = HashMap<String, Monkey>;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        parse_input(text)
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        process_part1(map).into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        process_part2(map).into()
+    }
+}
+
+#[derive(Debug)]
+pub enum Monkey {
+    Number(i64),
+    Math(String, String, Operation),
+}
+
+impl FromStr for Monkey {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(number) = s.parse::<i64>() {
+            Ok(Monkey::Number(number))
+        } else {
+            let mut parts = s.split_whitespace();
+            match (parts.next(), parts.next(), parts.next(), parts.next()) {
+                (Some(left), Some(op), Some(right), None) => {
+                    let op = op.parse().map_err(|err: ParseError| err.at(s, op))?;
+                    Ok(Monkey::Math(left.to_string(), right.to_string(), op))
+                }
+                _ => Err(ParseError::expected("<n> or <name> <operation> <name>")),
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Operation {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    LeftSubtract,
+    LeftDivide,
+}
+
+impl FromStr for Operation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "+" => Ok(Operation::Add),
+            "-" => Ok(Operation::Subtract),
+            "*" => Ok(Operation::Multiply),
+            "/" => Ok(Operation::Divide),
+            _ => Err(ParseError::expected("+, -, * or /")),
+        }
+    }
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operation::Add => write!(f, "+"),
+            Operation::Subtract => write!(f, "-"),
+            Operation::Multiply => write!(f, "*"),
+            Operation::Divide => write!(f, "/"),
+            Operation::LeftSubtract => write!(f, "-"),
+            Operation::LeftDivide => write!(f, "/"),
+        }
+    }
+}
+
+/// Parse input into a map of monkeys
+/// The key is the monkey's name
+/// Examples:
+/// root: pppw + sjmn
+/// dbpl: 5
+/// cczh: sllz + lgvd
+/// zczc: 2
+/// ptdq: humn - dvpt
+fn parse_input(text: &str) -> Result<HashMap<String, Monkey>, ParseError> {
+    let monkeys = lines_with(text, |line| {
+        let (name, monkey) = line
+            .split_once(": ")
+            .ok_or_else(|| ParseError::expected("<name>: <job>"))?;
+        let monkey = monkey
+            .parse()
+            .map_err(|err: ParseError| err.at(line, monkey))?;
+        Ok((name.to_string(), monkey))
+    })?;
+    let mut map = HashMap::new();
+    for (index, (name, monkey)) in monkeys.into_iter().enumerate() {
+        if map.contains_key(&name) {
+            return Err(ParseError::expected("a monkey not named above")
+                .at_column(1)
+                .offset_line(index));
+        }
+        map.insert(name, monkey);
+    }
+
+    // Every monkey a job depends on has to exist, and the root monkey compares two monkeys
+    for (index, line) in text.lines().enumerate() {
+        let (name, _) = line.split_once(": ").unwrap_or_default();
+        if let Some(Monkey::Math(left, right, _)) = map.get(name) {
+            if let Some(missing) = [left, right].into_iter().find(|m| !map.contains_key(*m)) {
+                let column = line.rfind(missing.as_str()).unwrap_or_default() + 1;
+                return Err(ParseError::expected("<name> of a monkey")
+                    .at_column(column)
+                    .offset_line(index));
+            }
+        }
+    }
+    if !matches!(map.get("root"), Some(Monkey::Math(..))) {
+        return Err(ParseError::expected("root: <name> <operation> <name>"));
+    }
+    if !map.contains_key("humn") {
+        return Err(ParseError::expected("humn: <n>"));
+    }
+    check_no_cycles(text, &map)?;
+    check_no_division_by_zero(text, &map)?;
+    Ok(map)
+}
+
+/// Checks that no monkey ends up waiting for itself through the monkeys it waits for, as it
+/// would never yell its number.
+fn check_no_cycles(text: &str, map: &HashMap<String, Monkey>) -> Result<(), ParseError> {
+    let lines: HashMap<&str, (usize, &str)> = text
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (line.split_once(": ").unwrap_or_default().0, (index, line)))
+        .collect();
+    // The monkeys known to yell their number eventually
+    let mut done = HashSet::new();
+    for name in text
+        .lines()
+        .map(|line| line.split_once(": ").unwrap_or_default().0)
+    {
+        if done.contains(name) {
+            continue;
+        }
+        // Depth first through the monkeys waited for, with how many of them have been visited
+        let mut path = vec![(name, 0)];
+        while let Some(&(current, visited)) = path.last() {
+            let next = match map.get(current) {
+                Some(Monkey::Math(left, right, _)) => [left, right].get(visited).copied(),
+                _ => None,
+            };
+            let Some(next) = next else {
+                done.insert(current);
+                path.pop();
+                continue;
+            };
+            path.last_mut().unwrap().1 += 1;
+            if done.contains(next.as_str()) {
+                continue;
+            }
+            if path.iter().any(|&(other, _)| other == next) {
+                let (index, line) = lines[current];
+                let (_, job) = line.split_once(": ").unwrap_or_default();
+                let column =
+                    line.len() - job.len() + job.find(next.as_str()).unwrap_or_default() + 1;
+                return Err(ParseError::expected(format!(
+                    "a monkey that doesn't wait for {}",
+                    current
+                ))
+                .at_column(column)
+                .offset_line(index));
+            }
+            path.push((next, 0));
+        }
+    }
+    Ok(())
+}
+
+/// Checks that no monkey divides by a monkey that yells 0. The monkeys that don't wait for the
+/// human yell the same numbers in both parts.
+fn check_no_division_by_zero(text: &str, map: &HashMap<String, Monkey>) -> Result<(), ParseError> {
+    let mut values = HashMap::new();
+    for (index, line) in text.lines().enumerate() {
+        let (name, job) = line.split_once(": ").unwrap_or_default();
+        if let Some(Monkey::Math(_, divisor, Operation::Divide)) = map.get(name) {
+            if yelled(divisor, map, &mut values) == 0 {
+                let column = line.len() - job.len() + job.rfind(divisor.as_str()).unwrap() + 1;
+                return Err(
+                    ParseError::expected("<name> of a monkey that doesn't yell 0")
+                        .at_column(column)
+                        .offset_line(index),
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The number the monkey yells, remembering the numbers of the monkeys it waits for. A division
+/// by 0 comes out as 0, to be reported where the divisor is checked.
+fn yelled<'a>(
+    name: &'a str,
+    map: &'a HashMap<String, Monkey>,
+    values: &mut HashMap<&'a str, i64>,
+) -> i64 {
+    if let Some(&value) = values.get(name) {
+        return value;
+    }
+    let value = match &map[name] {
+        Monkey::Number(n) => *n,
+        Monkey::Math(left, right, op) => {
+            let left = yelled(left, map, values);
+            let right = yelled(right, map, values);
+            match op {
+                Operation::Add => left.wrapping_add(right),
+                Operation::Subtract => left.wrapping_sub(right),
+                Operation::Multiply => left.wrapping_mul(right),
+                _ => left.checked_div(right).unwrap_or(0),
+            }
+        }
+    };
+    values.insert(name, value);
+    value
+}
+
+fn find_value_for_monkey(monkey: &Monkey, map: &HashMap<String, Monkey>) -> i64 {
+    match monkey {
+        Monkey::Number(n) => *n,
+        Monkey::Math(left, right, op) => {
+            let left_value = find_value_for_monkey(map.get(left).unwrap(), map);
+            let right_value = find_value_for_monkey(map.get(right).unwrap(), map);
+            match op {
+                Operation::Add => left_value + right_value,
+                Operation::Subtract => left_value - right_value,
+                Operation::Multiply => left_value * right_value,
+                Operation::Divide => left_value / right_value,
+                Operation::LeftSubtract => right_value - left_value,
+                Operation::LeftDivide => right_value / left_value,
+            }
+        }
+    }
+}
+
+fn process_part1(map: &HashMap<String, Monkey>) -> i64 {
+    find_value_for_monkey(map.get("root").unwrap(), map)
+}
+
+#[derive(Debug, Clone)]
+enum Math {
+    Number(i64),
+    Human(Vec<(i64, Operation)>),
+}
+
+/// Find the values of the monkeys. The monkey with the name "humn" is the one we are looking for.
+/// That will be our x.
+fn find_math(id: &String, monkey: &Monkey, map: &HashMap<String, Monkey>) -> Math {
+    if id == "humn" {
+        return Human(vec![]);
+    }
+    match monkey {
+        Monkey::Number(n) => Number(*n),
+        Monkey::Math(left, right, op) => {
+            let left_value = find_math(left, map.get(left).unwrap(), map);
+            let right_value = find_math(right, map.get(right).unwrap(), map);
+
+            match (left_value, right_value) {
+                (Number(left), Number(right)) => match op {
+                    Operation::Add => Number(left + right),
+                    Operation::Subtract => Number(left - right),
+                    Operation::Multiply => Number(left * right),
+                    Operation::Divide => Number(left / right),
+                    Operation::LeftSubtract => Number(right - left),
+                    Operation::LeftDivide => Number(right / left),
+                },
+                (Number(left), Human(mut human)) => {
+                    human.push((
+                        left,
+                        match op {
+                            Operation::Subtract => LeftSubtract,
+                            Operation::Divide => LeftDivide,
+                            x => x.clone(),
+                        },
+                    ));
+                    Human(human)
+                }
+                (Human(mut human), Number(right)) => {
+                    human.push((right, op.clone()));
+                    Human(human)
+                }
+                _ => panic!("Not implemented"),
+            }
+        }
+    }
+}
+
+fn unwrap_human(human: &Math, value: &Math) -> i64 {
+    let Human(vec) = human else {
+        panic!("Not a human");
+    };
+    let Number(mut result) = value else {
+        panic!("Not a number");
+    };
+
+    for (n, op) in vec.iter().rev() {
+        match op {
+            Operation::Add => result -= n,
+            Operation::Subtract => result += n,
+            Operation::Multiply => result /= n,
+            Operation::Divide => result *= n,
+            Operation::LeftSubtract => result = n - result,
+            Operation::LeftDivide => result = n / result,
+        }
+    }
+    result
+}
+
+fn process_part2(map: &HashMap<String, Monkey>) -> i64 {
+    let (left, right) = if let Monkey::Math(left, right, _) = map.get("root").unwrap() {
+        (left, right)
+    } else {
+        panic!("root is not a math operation");
+    };
+
+    let left_value = find_math(left, map.get(left).unwrap(), map);
+    let right_value = find_math(right, map.get(right).unwrap(), map);
+
+    if let Human(_) = left_value {
+        unwrap_human(&left_value, &right_value)
+    } else {
+        unwrap_human(&right_value, &left_value)
+    }
+}
+
+#[test]
+fn test_example() {
+    let input = common::parse_input::<Day21>(include_str!("../example.txt")).unwrap();
+    assert_eq!(Day21::part1(&input), Answer::from(152));
+    assert_eq!(Day21::part2(&input), Answer::from(301));
+}
+
+#[test]
+fn test_invalid_monkeys() {
+    let error = |text: &str| part1(text).unwrap_err().to_string();
+    assert_eq!(
+        error("root: aaaa + bbbb\naaaa: bbbb * cccc\nbbbb: aaaa - humn\ncccc: 1\nhumn: 5"),
+        "day21 line 3 column 7: expected \"a monkey that doesn't wait for bbbb\""
+    );
+    assert_eq!(
+        error("root: humn + aaaa\naaaa: aaaa * humn\nhumn: 5"),
+        "day21 line 2 column 7: expected \"a monkey that doesn't wait for aaaa\""
+    );
+    assert_eq!(
+        error("root: humn + aaaa\naaaa: 2\nhumn: 5\naaaa: 3"),
+        "day21 line 4 column 1: expected \"a monkey not named above\""
+    );
+}
+
+#[test]
+fn test_division_by_zero() {
+    let error = |text: &str| part1(text).unwrap_err().to_string();
+    assert_eq!(
+        error("root: aaaa + humn\naaaa: bbbb / cccc\nbbbb: 4\ncccc: dddd - bbbb\ndddd: 4\nhumn: 5"),
+        "day21 line 2 column 14: expected \"<name> of a monkey that doesn't yell 0\""
+    );
+    assert_eq!(
+        error("root: aaaa + humn\naaaa: bbbb / humn\nbbbb: 4\nhumn: 0"),
+        "day21 line 2 column 14: expected \"<name> of a monkey that doesn't yell 0\""
+    );
+}
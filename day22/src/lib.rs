@@ -0,0 +1,55 @@
+use common::{Answer, ParseError, Solution};
+
+mod part1;
+mod part2;
+
+pub struct Day22;
+
+common::solution_functions!(Day22);
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+
+    // The first part walks a flat map while the second folds it into a cube, so each part parses
+    // the notes into its own map.
+    type Input = String;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        part1::check_notes(text)?;
+        part2::check_notes(text)?;
+        Ok(text.to_string())
+    }
+
+    fn part1(notes: &Self::Input) -> Answer {
+        part1::process_part1(notes).into()
+    }
+
+    fn part2(notes: &Self::Input) -> Answer {
+        part2::process_part2(notes).into()
+    }
+}
+
+#[test]
+fn test_example() {
+    let input = common::parse_input::<Day22>(include_str!("../example.txt")).unwrap();
+    assert_eq!(Day22::part1(&input), Answer::from(6032));
+    assert_eq!(Day22::part2(&input), Answer::from(5031));
+}
+
+#[test]
+fn test_maps_that_are_not_cubes() {
+    let error = |text: &str| part2(text).unwrap_err().to_string();
+    let expected = "expected \"6 faces of the same size that fold into a cube\"";
+    // A single face, and six faces in a rectangle that would fold onto each other
+    assert_eq!(error("..\n..\n\n1"), format!("day22 line 3: {}", expected));
+    assert_eq!(
+        error("...\n...\n\n1"),
+        format!("day22 line 2 column 2: {}", expected)
+    );
+    // A face missing tiles
+    let example = include_str!("../example.txt");
+    assert_eq!(
+        error(&example.replace("        ...#....\n", "        ...#\n")),
+        format!("day22 line 9 column 13: {}", expected)
+    );
+}
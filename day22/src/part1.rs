@@ -0,0 +1,357 @@
+use common::parse::number;
+use common::Direction::{Down, Left, Right, Up};
+use common::{Direction, Grid, ParseError, Point};
+use Tile::{Air, Path, Rock};
+
+#[derive(Debug)]
+enum Operation {
+    Move(i32),
+    RotateLeft,
+    RotateRight,
+}
+
+/// Parse operations from string
+/// Example: "10L2" -> (Move(10), RotateLeft(), Move(2))
+fn parse_operations(text: &str) -> Result<Vec<Operation>, ParseError> {
+    let text = text.trim_end();
+    let mut operations = Vec::new();
+    let mut number_start = 0;
+
+    for (index, c) in text.char_indices() {
+        if c.is_ascii_digit() {
+            continue;
+        }
+
+        let number = number(text, &text[number_start..index], "<steps>")?;
+        operations.push(Operation::Move(number));
+
+        match c {
+            'L' => operations.push(Operation::RotateLeft),
+            'R' => operations.push(Operation::RotateRight),
+            _ => return Err(ParseError::expected("L or R").at(text, &text[index..])),
+        }
+        number_start = index + c.len_utf8();
+    }
+
+    if number_start < text.len() {
+        let number = number(text, &text[number_start..], "<steps>")?;
+        operations.push(Operation::Move(number));
+    }
+
+    Ok(operations)
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+enum Tile {
+    Air,
+    Path,
+    Rock,
+}
+
+#[derive(Debug)]
+struct Map {
+    tiles: Grid<Tile>,
+}
+
+impl Map {
+    /// A map from rows of different lengths, filling up the shorter rows with air.
+    fn from_rows(mut rows: Vec<Vec<Tile>>) -> Map {
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        for row in &mut rows {
+            row.resize(width, Air);
+        }
+        let tiles = Grid::from_rows(rows).expect("rows are filled up to the same width");
+
+        Map { tiles }
+    }
+
+    fn at(&self, x: i32, y: i32) -> &Tile {
+        &self.tiles[Point::new(x, y)]
+    }
+
+    /// Get the tile next to the given position in right direction
+    /// If there is no tile, we loop around the map and return the first path tile
+    /// If there is a rock tile, we return the given position
+    fn get_right_tile(&self, position: Point) -> Point {
+        let mut x = position.x + 1;
+        let y = position.y;
+
+        let width = self.tiles.width() as i32;
+        if x >= width {
+            x = 0;
+        }
+
+        while let Air = self.at(x, y) {
+            x += 1;
+            if x >= width {
+                x = 0;
+            }
+        }
+
+        if let Rock = self.at(x, y) {
+            return position;
+        }
+
+        Point { x, y }
+    }
+
+    /// Get the tile next to the given position in left direction
+    /// If there is no tile, we loop around the map and return the first path tile
+    /// If there is a rock tile, we return the given position
+    fn get_left_tile(&self, position: Point) -> Point {
+        let mut x = position.x - 1;
+        let y = position.y;
+
+        let width = self.tiles.width() as i32;
+        if x < 0 {
+            x = width - 1;
+        }
+
+        while let Air = self.at(x, y) {
+            x -= 1;
+            if x < 0 {
+                x = width - 1;
+            }
+        }
+
+        if let Rock = self.at(x, y) {
+            return position;
+        }
+
+        Point { x, y }
+    }
+
+    /// Get the tile next to the given position in up direction
+    /// If there is no tile, we loop around the map and return the first path tile
+    /// If there is a rock tile, we return the given position
+    fn get_up_tile(&self, position: Point) -> Point {
+        let mut y = position.y - 1;
+        let x = position.x;
+
+        let height = self.tiles.height() as i32;
+        if y < 0 {
+            y = height - 1;
+        }
+
+        while let Air = self.at(x, y) {
+            y -= 1;
+            if y < 0 {
+                y = height - 1;
+            }
+        }
+
+        if let Rock = self.at(x, y) {
+            return position;
+        }
+
+        Point { x, y }
+    }
+
+    /// Get the tile next to the given position in down direction
+    /// If there is no tile, we loop around the map and return the first path tile
+    /// If there is a rock tile, we return the given position
+    fn get_down_tile(&self, position: Point) -> Point {
+        let mut y = position.y + 1;
+        let x = position.x;
+
+        let height = self.tiles.height() as i32;
+        if y >= height {
+            y = 0;
+        }
+
+        while let Air = self.at(x, y) {
+            y += 1;
+            if y >= height {
+                y = 0;
+            }
+        }
+
+        if let Rock = self.at(x, y) {
+            return position;
+        }
+
+        Point { x, y }
+    }
+
+    /// Get the tile next to the given position in the given direction
+    /// If there is no tile, we loop around the map and return the first path tile
+    /// If there is a rock tile, we return the given position
+    fn get_tile(&self, position: Point, direction: Direction) -> Point {
+        match direction {
+            Up => self.get_up_tile(position),
+            Down => self.get_down_tile(position),
+            Right => self.get_right_tile(position),
+            Left => self.get_left_tile(position),
+        }
+    }
+}
+
+#[test]
+fn test_get_right_tile() {
+    let map = Map::from_rows(vec![vec![Air, Air, Path, Path, Rock, Path]]);
+
+    assert_eq!(
+        map.get_right_tile(Point { x: 2, y: 0 }),
+        Point { x: 3, y: 0 }
+    );
+
+    assert_eq!(
+        map.get_right_tile(Point { x: 3, y: 0 }),
+        Point { x: 3, y: 0 }
+    );
+
+    assert_eq!(
+        map.get_right_tile(Point { x: 5, y: 0 }),
+        Point { x: 2, y: 0 }
+    );
+}
+
+#[test]
+fn test_get_left_tile() {
+    let map = Map::from_rows(vec![vec![Air, Air, Path, Path, Rock, Path]]);
+
+    assert_eq!(
+        map.get_left_tile(Point { x: 2, y: 0 }),
+        Point { x: 5, y: 0 }
+    );
+
+    assert_eq!(
+        map.get_left_tile(Point { x: 3, y: 0 }),
+        Point { x: 2, y: 0 }
+    );
+
+    assert_eq!(
+        map.get_left_tile(Point { x: 5, y: 0 }),
+        Point { x: 5, y: 0 }
+    );
+}
+
+#[test]
+fn test_get_up_tile() {
+    let map = Map::from_rows(vec![
+        vec![Air],
+        vec![Air],
+        vec![Air, Air],
+        vec![Air, Path],
+        vec![Air, Path],
+        vec![Air, Rock],
+        vec![Air, Path],
+    ]);
+
+    assert_eq!(map.get_up_tile(Point { x: 1, y: 4 }), Point { x: 1, y: 3 });
+
+    assert_eq!(map.get_up_tile(Point { x: 1, y: 3 }), Point { x: 1, y: 6 });
+
+    assert_eq!(map.get_up_tile(Point { x: 1, y: 6 }), Point { x: 1, y: 6 });
+}
+
+#[test]
+fn test_get_down_tile() {
+    let map = Map::from_rows(vec![
+        vec![Air],
+        vec![Air],
+        vec![Air, Air],
+        vec![Air, Path],
+        vec![Air, Path],
+        vec![Air, Rock],
+        vec![Air, Path],
+    ]);
+
+    assert_eq!(
+        map.get_down_tile(Point { x: 1, y: 3 }),
+        Point { x: 1, y: 4 }
+    );
+
+    assert_eq!(
+        map.get_down_tile(Point { x: 1, y: 6 }),
+        Point { x: 1, y: 3 }
+    );
+
+    assert_eq!(
+        map.get_down_tile(Point { x: 1, y: 4 }),
+        Point { x: 1, y: 4 }
+    );
+}
+
+fn parse_map(text: &str) -> Result<Map, ParseError> {
+    // The rows of the map only go up to their last tile, so they are parsed one by one
+    let rows = text
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            line.chars()
+                .enumerate()
+                .map(|(column, c)| match c {
+                    '.' => Ok(Path),
+                    '#' => Ok(Rock),
+                    ' ' => Ok(Air),
+                    _ => Err(ParseError::expected(". or # or space")
+                        .offset_line(index)
+                        .at_column(column + 1)),
+                })
+                .collect()
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Map::from_rows(rows))
+}
+
+fn parse_input(text: &str) -> Result<(Map, Vec<Operation>), ParseError> {
+    let (map, operations) = text
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::expected("an empty line between the map and the path"))?;
+
+    let path_line = map.lines().count() + 1;
+    let map = parse_map(map)?;
+    let operations = parse_operations(operations).map_err(|err| err.offset_line(path_line))?;
+
+    Ok((map, operations))
+}
+
+/// Checks that the notes can be read, so solving them later cannot fail.
+pub(crate) fn check_notes(text: &str) -> Result<(), ParseError> {
+    parse_input(text).map(|_| ())
+}
+
+pub fn process_part1(text: &str) -> i32 {
+    let (map, operations) = parse_input(text).expect("notes are checked when parsing");
+
+    let mut position = Point {
+        // The starting position is always at the top of the map with the first path tile
+        x: map
+            .tiles
+            .row(0)
+            .iter()
+            .position(|x| matches!(x, Path))
+            .unwrap() as i32,
+        y: 0,
+    };
+
+    let mut direction = Right;
+
+    for operation in operations {
+        match operation {
+            Operation::RotateLeft => direction = direction.turn_left(),
+            Operation::RotateRight => direction = direction.turn_right(),
+            Operation::Move(steps) => {
+                for _ in 0..steps {
+                    position = map.get_tile(position, direction);
+                }
+            }
+        }
+    }
+
+    let column = position.x + 1;
+    let row = position.y + 1;
+
+    let facing = match direction {
+        Right => 0,
+        Down => 1,
+        Left => 2,
+        Up => 3,
+    };
+
+    tracing::debug!(row, column, facing, "final position");
+
+    1000 * row + 4 * column + facing
+}
@@ -0,0 +1,470 @@
+use common::parse::number;
+use common::Direction::{self, Down, Left, Right, Up};
+use common::{ParseError, Point, SparseGrid};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use num_integer::gcd;
+use std::collections::{HashMap, VecDeque};
+use Tile::{Air, Path, Rock};
+
+#[derive(Debug)]
+enum Operation {
+    Move(i32),
+    RotateLeft,
+    RotateRight,
+}
+
+/// Parse operations from string
+/// Example: "10L2" -> (Move(10), RotateLeft(), Move(2))
+fn parse_operations(text: &str) -> Result<Vec<Operation>, ParseError> {
+    let text = text.trim_end();
+    let mut operations = Vec::new();
+    let mut number_start = 0;
+
+    for (index, c) in text.char_indices() {
+        if c.is_ascii_digit() {
+            continue;
+        }
+
+        let number = number(text, &text[number_start..index], "<steps>")?;
+        operations.push(Operation::Move(number));
+
+        match c {
+            'L' => operations.push(Operation::RotateLeft),
+            'R' => operations.push(Operation::RotateRight),
+            _ => return Err(ParseError::expected("L or R").at(text, &text[index..])),
+        }
+        number_start = index + c.len_utf8();
+    }
+
+    if number_start < text.len() {
+        let number = number(text, &text[number_start..], "<steps>")?;
+        operations.push(Operation::Move(number));
+    }
+
+    Ok(operations)
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+enum Tile {
+    Air,
+    Path,
+    Rock,
+}
+
+#[derive(Debug)]
+struct Map {
+    tiles: SparseGrid<Tile>,
+    width: usize,
+    height: usize,
+    cube_stitching: HashMap<(Point, Direction), (Point, Direction)>,
+}
+
+impl Map {
+    pub fn at(&self, pos: &Point) -> Tile {
+        *self.tiles.get(*pos).unwrap_or(&Air)
+    }
+
+    pub fn warp(&self, pos: Point, direction: Direction) -> (Point, Direction) {
+        assert_eq!(self.at(&pos), Path);
+        assert_eq!(self.at(&pos.step(direction)), Air);
+        *self.cube_stitching.get(&(pos, direction)).unwrap()
+    }
+
+    // Great thanks to https://gist.github.com/mgedmin/71d632e40d4de5c9486a4616ffb53208
+    fn build_cube(&mut self) -> Result<(), ParseError> {
+        // The faces are found where the map is, so a map that isn't a net of a cube is reported
+        // at the face that doesn't fit, or after the map when faces are missing.
+        let not_a_cube = || ParseError::expected("6 faces of the same size that fold into a cube");
+        let at_face = |pos: Point| {
+            not_a_cube()
+                .at_column(pos.x as usize + 1)
+                .offset_line(pos.y as usize)
+        };
+
+        // I think there might be foldings where this will give the wrong size (double the real
+        // one), but it works on both my example and my sample input.
+        let cube_size = gcd(self.width, self.height);
+        if cube_size == 0 {
+            return Err(not_a_cube());
+        }
+        let mut cube = 0;
+        let mut faces: Vec<Face> = Vec::new();
+        let mut face_at: HashMap<Point, usize> = HashMap::new();
+        for row in 0..self.height / cube_size {
+            for col in 0..self.width / cube_size {
+                let pos = Point::new((col * cube_size) as i32, (row * cube_size) as i32);
+                let tiles = (0..cube_size * cube_size)
+                    .map(|i| pos + Point::new((i % cube_size) as i32, (i / cube_size) as i32))
+                    .filter(|tile| self.at(tile) != Air)
+                    .count();
+                if tiles == 0 {
+                    continue;
+                }
+                if tiles < cube_size * cube_size || cube == 6 {
+                    return Err(at_face(pos));
+                }
+                face_at.insert(pos, cube);
+                faces.push(Face {
+                    position: pos,
+                    size: cube_size,
+                    which: None,
+                    flat_edges: HashMap::new(),
+                });
+                cube += 1;
+            }
+        }
+        if cube < 6 {
+            return Err(not_a_cube().offset_line(self.height));
+        }
+        faces[0].which = Some(CubeFace::Front);
+        faces[0].flat_edges.insert(Right, CubeFace::Right);
+        faces[0].flat_edges.insert(Left, CubeFace::Left);
+        faces[0].flat_edges.insert(Up, CubeFace::Top);
+        faces[0].flat_edges.insert(Down, CubeFace::Bottom);
+
+        let next_left: HashMap<(CubeFace, CubeFace), CubeFace> = HashMap::from([
+            // I'm sure this can be derived from the vertices somehow
+            ((CubeFace::Front, CubeFace::Top), CubeFace::Left),
+            ((CubeFace::Front, CubeFace::Left), CubeFace::Bottom),
+            ((CubeFace::Front, CubeFace::Bottom), CubeFace::Right),
+            ((CubeFace::Front, CubeFace::Right), CubeFace::Top),
+            ((CubeFace::Left, CubeFace::Top), CubeFace::Back),
+            ((CubeFace::Left, CubeFace::Back), CubeFace::Bottom),
+            ((CubeFace::Left, CubeFace::Bottom), CubeFace::Front),
+            ((CubeFace::Left, CubeFace::Front), CubeFace::Top),
+            ((CubeFace::Right, CubeFace::Top), CubeFace::Front),
+            ((CubeFace::Right, CubeFace::Front), CubeFace::Bottom),
+            ((CubeFace::Right, CubeFace::Bottom), CubeFace::Back),
+            ((CubeFace::Right, CubeFace::Back), CubeFace::Top),
+            ((CubeFace::Back, CubeFace::Top), CubeFace::Right),
+            ((CubeFace::Back, CubeFace::Right), CubeFace::Bottom),
+            ((CubeFace::Back, CubeFace::Bottom), CubeFace::Left),
+            ((CubeFace::Back, CubeFace::Left), CubeFace::Top),
+            ((CubeFace::Top, CubeFace::Front), CubeFace::Right),
+            ((CubeFace::Top, CubeFace::Right), CubeFace::Back),
+            ((CubeFace::Top, CubeFace::Back), CubeFace::Left),
+            ((CubeFace::Top, CubeFace::Left), CubeFace::Front),
+            ((CubeFace::Bottom, CubeFace::Front), CubeFace::Left),
+            ((CubeFace::Bottom, CubeFace::Left), CubeFace::Back),
+            ((CubeFace::Bottom, CubeFace::Back), CubeFace::Right),
+            ((CubeFace::Bottom, CubeFace::Right), CubeFace::Front),
+        ]);
+
+        let mut queue = VecDeque::from([0]);
+        while let Some(u) = queue.pop_front() {
+            let which_u = faces[u].which.unwrap();
+            for direction in [Right, Down, Left, Up] {
+                if let Some(v) = face_at.get(&faces[u].flat_sibling(direction)) {
+                    if faces[*v].which.is_some() {
+                        continue;
+                    }
+                    if !faces[u].flat_edges.contains_key(&direction) {
+                        continue;
+                    }
+                    let which_v = faces[u].flat_edges[&direction];
+                    faces[*v].which = Some(which_v);
+
+                    let mut insert = |direction, face| {
+                        faces[*v].flat_edges.insert(direction, face);
+                    };
+                    insert(direction, which_u.opposite());
+                    insert(direction.turn_left(), next_left[&(which_u, which_v)]);
+                    insert(
+                        direction.turn_right(),
+                        next_left[&(which_u, which_v)].opposite(),
+                    );
+                    insert(direction.opposite(), which_u);
+                    queue.push_back(*v);
+                }
+            }
+        }
+        // Every face has to end up on a side of its own
+        for (index, face) in faces.iter().enumerate() {
+            if face.which.is_none() || faces[..index].iter().any(|other| other.which == face.which)
+            {
+                return Err(at_face(face.position));
+            }
+        }
+
+        // A cube has 6 sides, 8 vertices and 12 edges
+        // In a folding, 5 edges stay connected, the other 7 get split into 14 unconnected square
+        // edges.
+
+        let mut stitchings: HashMap<CubeEdge, (Direction, CubeVertex, Vec<Point>)> = HashMap::new();
+        for face in faces {
+            for direction in [Right, Down, Left, Up] {
+                if !face_at.contains_key(&face.flat_sibling(direction)) {
+                    let (vertex, tiles) = face.edge_tiles(direction);
+                    let _pos = tiles[0];
+                    let other_face = face.flat_edges[&direction];
+                    let edge = face.common_edge(other_face).unwrap_or_else(|| {
+                        panic!(
+                            "No common edge between {:?} and {:?}!",
+                            face.which.unwrap(),
+                            other_face
+                        )
+                    });
+                    if let Some((other_direction, other_vertex, other_tiles)) =
+                        stitchings.get(&edge)
+                    {
+                        let pairs: Vec<(&Point, &Point)> = if vertex == *other_vertex {
+                            tiles.iter().zip(other_tiles).collect()
+                        } else {
+                            tiles.iter().rev().zip(other_tiles).collect()
+                        };
+                        for (pos, other) in pairs {
+                            self.cube_stitching
+                                .insert((*pos, direction), (*other, other_direction.opposite()));
+                            self.cube_stitching
+                                .insert((*other, *other_direction), (*pos, direction.opposite()));
+                        }
+                    } else {
+                        stitchings.insert(edge, (direction, vertex, tiles));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+// Let's number the vertices of the front face:
+//
+//    0 --- 1
+//    |     |
+//    |     |
+//    3 --- 2
+//
+// back face:
+//
+//    4 --- 5
+//    |     |
+//    |     |
+//    7 --- 6
+//
+// A set of vertices determines the face.
+#[repr(u8)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, IntoPrimitive)]
+enum CubeFace {
+    Front = 0b0000_1111,
+    Top = 0b0011_0011,
+    Bottom = 0b1100_1100,
+    Left = 0b1001_1001,
+    Right = 0b0110_0110,
+    Back = 0b1111_0000,
+}
+
+#[repr(u8)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, TryFromPrimitive)]
+enum CubeEdge {
+    FrontTop = 0b0000_0011,
+    FrontRight = 0b0000_0110,
+    FrontBottom = 0b0000_1100,
+    FrontLeft = 0b0000_1001,
+    BackTop = 0b0011_0000,
+    BackRight = 0b0110_0000,
+    BackBottom = 0b1100_0000,
+    BackLeft = 0b1001_0000,
+    LeftTop = 0b0001_0001,
+    LeftBottom = 0b1000_1000,
+    RightTop = 0b0010_0010,
+    RightBottom = 0b0100_0100,
+}
+
+#[repr(u8)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, TryFromPrimitive)]
+enum CubeVertex {
+    FrontTopLeft = 0b0000_0001,
+    FrontTopRight = 0b0000_0010,
+    FrontBottomRight = 0b0000_0100,
+    FrontBottomLeft = 0b0000_1000,
+    BackTopLeft = 0b0001_0000,
+    BackTopRight = 0b0010_0000,
+    BackBottomRight = 0b0100_0000,
+    BackBottomLeft = 0b1000_0000,
+}
+
+impl CubeFace {
+    fn opposite(self) -> CubeFace {
+        use CubeFace::*;
+        match self {
+            Front => Back,
+            Back => Front,
+            Left => Right,
+            Right => Left,
+            Top => Bottom,
+            Bottom => Top,
+        }
+    }
+
+    #[allow(dead_code)]
+    fn touching(self) -> [CubeFace; 4] {
+        use CubeFace::*;
+        match self {
+            Front => [Top, Left, Bottom, Right],
+            Left => [Top, Back, Bottom, Front],
+            Right => [Top, Front, Bottom, Back],
+            Back => [Top, Right, Bottom, Left],
+            Top => [Front, Right, Back, Left],
+            Bottom => [Front, Left, Back, Right],
+        }
+    }
+
+    fn common_edge(self, other: CubeFace) -> Option<CubeEdge> {
+        let a: u8 = self.into();
+        let b: u8 = other.into();
+        (a & b).try_into().ok()
+    }
+
+    fn common_vertex(self, other1: CubeFace, other2: CubeFace) -> Option<CubeVertex> {
+        let a: u8 = self.into();
+        let b: u8 = other1.into();
+        let c: u8 = other2.into();
+        (a & b & c).try_into().ok()
+    }
+}
+
+struct Face {
+    position: Point,
+    size: usize,
+    which: Option<CubeFace>,
+    flat_edges: HashMap<Direction, CubeFace>,
+}
+
+impl Face {
+    fn common_edge(&self, other: CubeFace) -> Option<CubeEdge> {
+        self.which?.common_edge(other)
+    }
+
+    fn common_vertex(&self, other1: CubeFace, other2: CubeFace) -> Option<CubeVertex> {
+        self.which?.common_vertex(other1, other2)
+    }
+
+    fn edge_tiles(&self, direction: Direction) -> (CubeVertex, Vec<Point>) {
+        let n = self.size as i32;
+        let last = n - 1;
+        let vertex = self
+            .common_vertex(
+                self.flat_edges[&direction],
+                self.flat_edges[&direction.turn_left()],
+            )
+            .unwrap();
+        let tiles = match direction {
+            Right => (0..n)
+                .map(|n| self.position + Point::new(last, n))
+                .collect(),
+            Down => (0..n)
+                .rev()
+                .map(|n| self.position + Point::new(n, last))
+                .collect(),
+            Left => (0..n)
+                .rev()
+                .map(|n| self.position + Point::new(0, n))
+                .collect(),
+            Up => (0..n).map(|n| self.position + Point::new(n, 0)).collect(),
+        };
+        (vertex, tiles)
+    }
+
+    fn flat_sibling(&self, direction: Direction) -> Point {
+        let size = self.size as i32;
+        self.position + Point::new(direction.offset().x * size, direction.offset().y * size)
+    }
+}
+
+fn parse_map(text: &str) -> Result<Map, ParseError> {
+    let grid = SparseGrid::parse(text, ' ', ". or # or space", |c| match c {
+        '#' => Some(Rock),
+        '.' => Some(Path),
+        _ => None,
+    })?;
+
+    let width = text.lines().map(|l| l.len()).max().unwrap_or(0);
+    let height = text.lines().count();
+
+    Ok(Map {
+        tiles: grid,
+        width,
+        height,
+        cube_stitching: HashMap::new(),
+    })
+}
+
+fn parse_input(text: &str) -> Result<(Map, Vec<Operation>), ParseError> {
+    let (map, operations) = text
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::expected("an empty line between the map and the path"))?;
+
+    let path_line = map.lines().count() + 1;
+    let map = parse_map(map)?;
+    let operations = parse_operations(operations).map_err(|err| err.offset_line(path_line))?;
+
+    Ok((map, operations))
+}
+
+/// Checks that the notes can be read and the map folds into a cube, so solving them later
+/// cannot fail.
+pub(crate) fn check_notes(text: &str) -> Result<(), ParseError> {
+    let (mut map, _) = parse_input(text)?;
+    map.build_cube()
+}
+
+pub fn process_part2(text: &str) -> usize {
+    let (mut map, operations) = parse_input(text).expect("notes are checked when parsing");
+
+    map.build_cube().expect("notes are checked when parsing");
+
+    let start_column = map
+        .tiles
+        .iter()
+        .filter(|(position, tile)| tile == &&Path && position.y == 0)
+        .map(|(position, _)| position.x)
+        .min()
+        .unwrap();
+
+    // The starting position is always at the top of the map with the first path tile
+    let mut position = Point::new(start_column, 0);
+
+    let mut direction = Right;
+
+    for operation in operations {
+        match operation {
+            Operation::RotateLeft => direction = direction.turn_left(),
+            Operation::RotateRight => direction = direction.turn_right(),
+            Operation::Move(steps) => {
+                for _ in 0..steps {
+                    let mut next = position.step(direction);
+                    let mut next_facing = direction;
+
+                    if map.at(&next) == Air {
+                        (next, next_facing) = map.warp(position, direction);
+                    }
+
+                    match map.at(&next) {
+                        Rock => break,
+                        Path => {
+                            position = next;
+                            direction = next_facing;
+                        }
+                        Air => panic!("Unexpected air tile"),
+                    }
+                }
+            }
+        }
+    }
+
+    // Rows and columns are counted from 1
+    let column = position.x as usize + 1;
+    let row = position.y as usize + 1;
+
+    let facing = match direction {
+        Right => 0,
+        Down => 1,
+        Left => 2,
+        Up => 3,
+    };
+
+    tracing::debug!(row, column, facing, "final position");
+
+    1000 * row + 4 * column + facing
+}
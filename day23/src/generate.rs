@@ -0,0 +1,25 @@
+//! Random fields of elves, to check the solutions against each other on more than the example.
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// A small square field in the format of the puzzle input, with at least one elf. The same seed
+/// always gives the same field.
+pub fn input(seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = rng.gen_range(1..=12);
+    let density = rng.gen_range(0.1..0.7);
+
+    let mut rows: Vec<Vec<char>> = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| if rng.gen_bool(density) { '#' } else { '.' })
+                .collect()
+        })
+        .collect();
+    rows[size / 2][size / 2] = '#';
+
+    rows.into_iter()
+        .map(|row| row.into_iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
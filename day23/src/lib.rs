@@ -0,0 +1,259 @@
+use common::{budget, frames, Answer, Direction, ParseError, Point, Solution, SparseGrid};
+use itertools::Itertools;
+use rayon::prelude::*;
+use std::collections::VecDeque;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+pub mod generate;
+
+pub struct Day23;
+
+common::solution_functions!(Day23);
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    type Input = Map;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        Map::from_str(text)
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        process_part1(map).into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        process_part2(map).into()
+    }
+}
+
+impl Day23 {
+    /// Solve the second part without rayon, as a reference for the parallel version.
+    pub fn part2_slow(map: &Map) -> Answer {
+        process_part2_slow(map).into()
+    }
+}
+
+/// A spot taken by an elf.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct Elf;
+
+impl Display for Elf {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#")
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    elves: SparseGrid<Elf>,
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let elves = SparseGrid::parse(s, '.', "# or .", |c| (c == '#').then_some(Elf))?;
+
+        if elves.is_empty() {
+            return Err(ParseError::expected("at least one elf (#)"));
+        }
+        Ok(Map { elves })
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.elves)
+    }
+}
+
+/// Finds the points of interest in the given direction.
+/// These are the three points that are adjacent to the given point in the given direction.
+/// So for up the points are the point above and its two adjacent points.
+fn points_of_interest(point: Point, direction: Direction) -> [Point; 3] {
+    let ahead = point.step(direction);
+    [
+        ahead,
+        ahead.step(direction.turn_left()),
+        ahead.step(direction.turn_right()),
+    ]
+}
+
+/// The directions the elves look in during the first round, in order.
+fn initial_directions() -> VecDeque<Direction> {
+    VecDeque::from(vec![
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ])
+}
+
+fn find_next_spot(map: &Map, point: Point, directions: &VecDeque<Direction>) -> Point {
+    // If there is nobody in the 8 adjacent spots, we stay at the same spot.
+    if point.adjacent().all(|p| !map.elves.contains(p)) {
+        return point;
+    }
+
+    // Find the first direction that has an empty spot.
+    for direction in directions {
+        let points_of_interest = points_of_interest(point, *direction);
+        if points_of_interest.iter().all(|p| !map.elves.contains(*p)) {
+            return points_of_interest[0];
+        }
+    }
+
+    // If there is no empty spot, we stay at the same spot.
+    point
+}
+
+fn process_part1(map: &Map) -> i32 {
+    let mut map = map.clone();
+    let mut directions = initial_directions();
+
+    for round in 1..=10 {
+        let mut moving_to = map
+            .elves
+            .points()
+            .map(|p| (p, find_next_spot(&map, p, &directions)))
+            .collect::<Vec<_>>();
+
+        // if all of them are moving to the same spot, we are done
+        if moving_to.iter().all(|(old, new)| old == new) {
+            break;
+        }
+
+        // If two elves want to move to the same spot, none of them move.
+        // So we remove all those pairs.
+        let mut to_stay_same = Vec::new();
+        for (p1, p2) in moving_to.iter() {
+            if moving_to.iter().filter(|(_, p)| p == p2).count() > 1 {
+                to_stay_same.push(*p1);
+            }
+        }
+
+        moving_to.retain(|(p, _)| !to_stay_same.contains(p));
+        // Add back the elves that are staying at the same spot.
+        moving_to.extend(to_stay_same.iter().map(|p| (*p, *p)));
+
+        // Update the map
+        map.elves = moving_to.iter().map(|(_, p)| (*p, Elf)).collect();
+        frames::record(|| format!("Round {}\n{}", round, map));
+
+        // Rotate the directions so that the first direction becomes the last one.
+        let first = directions.pop_front().unwrap();
+        directions.push_back(first);
+    }
+
+    let (min, max) = map.elves.bounds().unwrap();
+
+    (min.x..=max.x)
+        .cartesian_product(min.y..=max.y)
+        .filter(|(x, y)| !map.elves.contains(Point::new(*x, *y)))
+        .count() as i32
+}
+
+fn process_part2_slow(map: &Map) -> i32 {
+    let mut map = map.clone();
+    let mut directions = initial_directions();
+
+    let mut rounds = 0;
+    loop {
+        rounds += 1;
+        budget::check(|| format!("{} rounds", rounds - 1));
+        let mut moving_to = map
+            .elves
+            .points()
+            .map(|p| (p, find_next_spot(&map, p, &directions)))
+            .collect::<Vec<_>>();
+
+        // if all of them are moving to the same spot, we are done
+        if moving_to.iter().all(|(old, new)| old == new) {
+            break;
+        }
+
+        // If two elves want to move to the same spot, none of them move.
+        // So we remove all those pairs.
+        let mut to_stay_same = Vec::new();
+        for (p1, p2) in moving_to.iter() {
+            if moving_to.iter().filter(|(_, p)| p == p2).count() > 1 {
+                to_stay_same.push(*p1);
+            }
+        }
+
+        moving_to.retain(|(p, _)| !to_stay_same.contains(p));
+        // Add back the elves that are staying at the same spot.
+        moving_to.extend(to_stay_same.iter().map(|p| (*p, *p)));
+
+        // Update the map
+        map.elves = moving_to.iter().map(|(_, p)| (*p, Elf)).collect();
+
+        // Rotate the directions so that the first direction becomes the last one.
+        let first = directions.pop_front().unwrap();
+        directions.push_back(first);
+    }
+
+    rounds
+}
+
+fn process_part2(map: &Map) -> i32 {
+    let mut map = map.clone();
+    let mut directions = initial_directions();
+
+    let mut rounds = 0;
+    loop {
+        rounds += 1;
+        budget::check(|| format!("{} rounds", rounds - 1));
+        let elves = map.elves.points().collect::<Vec<_>>();
+        let moving_to = elves
+            .par_iter()
+            .map(|p| (*p, find_next_spot(&map, *p, &directions)))
+            .collect::<Vec<_>>();
+
+        // if all of them are moving to the same spot, we are done
+        if moving_to.iter().all(|(old, new)| old == new) {
+            break;
+        }
+
+        map.elves = moving_to
+            .par_iter()
+            .map(|(old, new)| {
+                if moving_to.iter().filter(|(_, p2)| p2 == new).count() > 1 {
+                    (*old, Elf)
+                } else {
+                    (*new, Elf)
+                }
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .collect();
+        frames::record(|| format!("Round {}\n{}", rounds, map));
+
+        // Rotate the directions so that the first direction becomes the last one.
+        let first = directions.pop_front().unwrap();
+        directions.push_back(first);
+    }
+
+    rounds
+}
+
+#[test]
+fn test_example() {
+    let input = common::parse_input::<Day23>(include_str!("../example.txt")).unwrap();
+    assert_eq!(Day23::part1(&input), Answer::from(110));
+    assert_eq!(Day23::part2(&input), Answer::from(20));
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #![proptest_config(proptest::test_runner::Config::with_cases(64))]
+
+    #[test]
+    fn test_part2_matches_slow_part2(seed in proptest::prelude::any::<u64>()) {
+        let map = common::parse_input::<Day23>(&generate::input(seed)).unwrap();
+        proptest::prop_assert_eq!(process_part2(&map), process_part2_slow(&map));
+    }
+}
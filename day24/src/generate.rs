@@ -0,0 +1,36 @@
+//! Random valleys, to check the solutions against each other on more than the example.
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// A small valley in the format of the puzzle input, with the entrance at the left of the top wall
+/// and the exit at the right of the bottom wall. Like in the puzzle, no blizzard moves up or down
+/// in the columns of the entrance and the exit, so none of them ever leaves the valley. The same
+/// seed always gives the same valley.
+pub fn input(seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let width = rng.gen_range(1..=10);
+    let height = rng.gen_range(1..=8);
+    let density = rng.gen_range(0.0..0.6);
+
+    let mut lines = Vec::new();
+    lines.push(format!("#.{}", "#".repeat(width)));
+    for _ in 0..height {
+        let ground = (1..=width)
+            .map(|x| {
+                if !rng.gen_bool(density) {
+                    return '.';
+                }
+                let vertical = x != 1 && x != width;
+                match rng.gen_range(0..if vertical { 4 } else { 2 }) {
+                    0 => '<',
+                    1 => '>',
+                    2 => '^',
+                    _ => 'v',
+                }
+            })
+            .collect::<String>();
+        lines.push(format!("#{}#", ground));
+    }
+    lines.push(format!("{}.#", "#".repeat(width)));
+    lines.join("\n")
+}
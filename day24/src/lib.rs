@@ -0,0 +1,401 @@
+use common::{frames, Answer, Direction, Grid, ParseError, Point, Solution};
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+
+use common::search::bfs;
+use std::iter::once;
+use std::str::FromStr;
+
+pub mod generate;
+
+pub struct Day24;
+
+common::solution_functions!(Day24);
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Input = Valley;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        text.parse()
+    }
+
+    fn part1(valley: &Self::Input) -> Answer {
+        process_part1(valley).into()
+    }
+
+    fn part2(valley: &Self::Input) -> Answer {
+        process_part2(valley).into()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Tile {
+    Wall,
+    Ground,
+    Blizzard(Direction),
+}
+
+fn parse_tile(c: char) -> Option<Tile> {
+    match c {
+        '#' => Some(Tile::Wall),
+        '.' => Some(Tile::Ground),
+        '^' => Some(Tile::Blizzard(Direction::Up)),
+        'v' => Some(Tile::Blizzard(Direction::Down)),
+        '<' => Some(Tile::Blizzard(Direction::Left)),
+        '>' => Some(Tile::Blizzard(Direction::Right)),
+        _ => None,
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tile::Wall => write!(f, "#"),
+            Tile::Ground => write!(f, "."),
+            Tile::Blizzard(Direction::Up) => write!(f, "^"),
+            Tile::Blizzard(Direction::Down) => write!(f, "v"),
+            Tile::Blizzard(Direction::Left) => write!(f, "<"),
+            Tile::Blizzard(Direction::Right) => write!(f, ">"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+struct Blizzard {
+    position: Point,
+    direction: Direction,
+}
+
+impl Blizzard {
+    /// Move the blizzard one step in the direction it is facing
+    /// If it hits a wall, it turns wraps around the valley
+    /// The walls are the outermost rows and columns
+    fn simulate(&self, valley: &Valley) -> Blizzard {
+        let width = valley.tiles.width();
+        let height = valley.tiles.height();
+        let new_position = match self.direction {
+            Direction::Up => Point {
+                x: self.position.x,
+                y: if self.position.y <= 1 {
+                    (height - 2) as i32
+                } else {
+                    self.position.y - 1
+                },
+            },
+            Direction::Down => Point {
+                x: self.position.x,
+                y: if self.position.y >= (height - 2) as i32 {
+                    1
+                } else {
+                    self.position.y + 1
+                },
+            },
+            Direction::Left => Point {
+                x: if self.position.x <= 1 {
+                    (width - 2) as i32
+                } else {
+                    self.position.x - 1
+                },
+                y: self.position.y,
+            },
+            Direction::Right => Point {
+                x: if self.position.x >= (width - 2) as i32 {
+                    1
+                } else {
+                    self.position.x + 1
+                },
+                y: self.position.y,
+            },
+        };
+
+        Blizzard {
+            position: new_position,
+            direction: self.direction,
+        }
+    }
+
+    /// Where the blizzard is after the given number of minutes, wrapping around the valley as
+    /// often as needed.
+    fn after(&self, valley: &Valley, minutes: usize) -> Point {
+        let width = valley.tiles.width() as i64 - 2;
+        let height = valley.tiles.height() as i64 - 2;
+        let offset = self.direction.offset();
+        let minutes = minutes as i64;
+        Point {
+            x: (1 + (self.position.x as i64 - 1 + offset.x as i64 * minutes).rem_euclid(width))
+                as i32,
+            y: (1 + (self.position.y as i64 - 1 + offset.y as i64 * minutes).rem_euclid(height))
+                as i32,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Valley {
+    // The walls and the ground of the valley, the blizzards move over the ground
+    tiles: Grid<Tile>,
+    blizzards: Vec<Blizzard>,
+    start: Point,
+    end: Point,
+}
+
+impl FromStr for Valley {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tiles = Grid::parse(s, "#, ., ^, v, < or >", parse_tile)?;
+        let height = tiles.height();
+        if height < 3 {
+            return Err(ParseError::expected("a valley of at least three rows"));
+        }
+
+        let opening = |y: usize| tiles.row(y).iter().position(|t| *t == Tile::Ground);
+        let start = Point {
+            x: opening(0).ok_or_else(|| ParseError::expected("an opening (.) in the top wall"))?
+                as i32,
+            y: 0,
+        };
+        let end = Point {
+            x: opening(height - 1).ok_or_else(|| {
+                ParseError::expected("an opening (.) in the bottom wall").offset_line(height - 1)
+            })? as i32,
+            y: (height - 1) as i32,
+        };
+
+        let blizzards = tiles
+            .iter()
+            .filter_map(|(position, tile)| match tile {
+                Tile::Blizzard(direction) => Some(Blizzard {
+                    position,
+                    direction: *direction,
+                }),
+                _ => None,
+            })
+            .collect();
+        let tiles = tiles.map(|tile| match tile {
+            Tile::Wall => Tile::Wall,
+            _ => Tile::Ground,
+        });
+
+        Ok(Valley {
+            tiles,
+            blizzards,
+            start,
+            end,
+        })
+    }
+}
+
+impl Valley {
+    fn is_valid_position(&self, position: Point) -> bool {
+        // The walls surround the valley, except for the start and the end
+        self.tiles.get(position) == Some(&Tile::Ground)
+    }
+}
+
+/// The blizzards at every minute up to the latest one asked for, simulated as the search gets
+/// further in time. The blizzards wrap around, so they are back where they started after a
+/// period, and from then on the same minutes repeat.
+struct Forecast<'a> {
+    valley: &'a Valley,
+    period: usize,
+    blizzards: Vec<Blizzard>,
+    occupied: Vec<HashSet<Point>>,
+}
+
+impl<'a> Forecast<'a> {
+    fn new(valley: &'a Valley) -> Self {
+        // Every blizzard is back after crossing the width or the height of the valley
+        let width = valley.tiles.width() - 2;
+        let height = valley.tiles.height() - 2;
+        let period = width / gcd(width, height) * height;
+
+        Forecast {
+            valley,
+            period,
+            blizzards: valley.blizzards.clone(),
+            occupied: vec![valley.blizzards.iter().map(|b| b.position).collect()],
+        }
+    }
+
+    /// The points with a blizzard at the given minute.
+    fn occupied(&mut self, minute: usize) -> &HashSet<Point> {
+        let minute = minute % self.period;
+        while self.occupied.len() <= minute {
+            self.blizzards = self
+                .blizzards
+                .iter()
+                .map(|blizzard| blizzard.simulate(self.valley))
+                .collect();
+            self.occupied
+                .push(self.blizzards.iter().map(|b| b.position).collect());
+        }
+        &self.occupied[minute]
+    }
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// The minute at which the expedition reaches `to` at the earliest, when it leaves `from` at
+/// the given minute, or `None` when the blizzards never let it through.
+#[tracing::instrument(level = "debug", skip(forecast))]
+fn cross(forecast: &mut Forecast, from: Point, to: Point, minute: u32) -> Option<u32> {
+    let valley = forecast.valley;
+    let period = forecast.period;
+
+    // The points the expedition could be at in the minute the search is at, drawn as a frame
+    // every time the search moves on to the next minute
+    let mut frontier = (minute, Vec::new());
+
+    // Being at the same point at the same minute of the period is the same as being there a
+    // period earlier, so the search ends even when there is no way through
+    let search = bfs(
+        [(from, minute as usize % period)],
+        |(point, at)| {
+            if frames::is_recording() {
+                if frontier.0 as usize % period != *at {
+                    frames::record(|| frame(valley, frontier.0, &frontier.1));
+                    frontier = (frontier.0 + 1, Vec::new());
+                }
+                frontier.1.push(*point);
+            }
+
+            // Every minute the expedition either waits or moves, but never into a wall or a blizzard
+            let minute = (at + 1) % period;
+            let occupied = forecast.occupied(minute);
+            once(*point)
+                .chain(point.neighbours())
+                .filter(|next| valley.is_valid_position(*next) && !occupied.contains(next))
+                .map(|next| (next, minute))
+                .collect::<Vec<_>>()
+        },
+        |(point, _)| *point == to,
+    );
+    frames::record(|| frame(valley, frontier.0, &frontier.1));
+    search.cost().map(|minutes| minute + minutes)
+}
+
+fn process_part1(valley: &Valley) -> u32 {
+    let mut forecast = Forecast::new(valley);
+    cross(&mut forecast, valley.start, valley.end, 0).unwrap()
+}
+
+// --- Part Two ---
+// As the expedition reaches the far side of the valley, one of the Elves looks especially dismayed:
+// He forgot his snacks at the entrance to the valley!
+// Since you're so good at dodging blizzards, the Elves humbly request that you go back for his snacks. From the same initial conditions, how quickly can you make it from the start to the goal, then back to the start, then back to the goal?
+// In the above example, the first trip to the goal takes 18 minutes, the trip back to the start takes 23 minutes, and the trip back to the goal again takes 13 minutes, for a total time of 54 minutes.
+// What is the fewest number of minutes required to reach the goal, go back to the start, then reach the goal again?
+
+fn process_part2(valley: &Valley) -> u32 {
+    let mut forecast = Forecast::new(valley);
+    // Reaching each end as early as possible never makes a later trip slower, as the expedition can
+    // always wait at the entrance or the exit
+    let minute = cross(&mut forecast, valley.start, valley.end, 0).unwrap();
+    let minute = cross(&mut forecast, valley.end, valley.start, minute).unwrap();
+    cross(&mut forecast, valley.start, valley.end, minute).unwrap()
+}
+
+/// Crosses the valley keeping every point the expedition could be at each minute, as a reference
+/// for the search. Gives up once it has seen every point at every minute of the period.
+#[cfg(test)]
+fn cross_minute_by_minute(valley: &Valley, from: Point, to: Point, minute: u32) -> Option<u32> {
+    let mut forecast = Forecast::new(valley);
+    let give_up =
+        minute as usize + forecast.period * (valley.tiles.width() * valley.tiles.height());
+
+    let mut choices = HashSet::from([from]);
+    let mut minute = minute;
+    while !choices.contains(&to) {
+        if minute as usize > give_up {
+            return None;
+        }
+        minute += 1;
+        let occupied = forecast.occupied(minute as usize);
+        choices = choices
+            .iter()
+            .flat_map(|choice| once(*choice).chain(choice.neighbours()))
+            .filter(|point| valley.is_valid_position(*point) && !occupied.contains(point))
+            .collect();
+    }
+    Some(minute)
+}
+
+/// Draws the valley at the given minute, with an E for every point the expedition could be at.
+/// Where blizzards meet, the number of blizzards is drawn instead of their direction.
+fn frame(valley: &Valley, minute: u32, choices: &[Point]) -> String {
+    let mut blizzards: HashMap<Point, Vec<Direction>> = HashMap::new();
+    for blizzard in &valley.blizzards {
+        blizzards
+            .entry(blizzard.after(valley, minute as usize))
+            .or_default()
+            .push(blizzard.direction);
+    }
+
+    let mut frame = format!("Minute {}\n", minute);
+    for (point, tile) in valley.tiles.iter() {
+        if point.x == 0 && point.y > 0 {
+            frame.push('\n');
+        }
+        if choices.contains(&point) {
+            frame.push('E');
+        } else if let Some(directions) = blizzards.get(&point) {
+            match directions.as_slice() {
+                [direction] => frame.push_str(&Tile::Blizzard(*direction).to_string()),
+                _ => frame.push_str(&directions.len().to_string()),
+            }
+        } else {
+            frame.push_str(&tile.to_string());
+        }
+    }
+    frame.push('\n');
+    frame
+}
+
+#[test]
+fn test_example() {
+    let input = common::parse_input::<Day24>(include_str!("../example.txt")).unwrap();
+    assert_eq!(Day24::part1(&input), Answer::from(18));
+    assert_eq!(Day24::part2(&input), Answer::from(54));
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #![proptest_config(proptest::test_runner::Config::with_cases(64))]
+
+    #[test]
+    fn test_search_matches_going_minute_by_minute(seed in proptest::prelude::any::<u64>()) {
+        let valley = common::parse_input::<Day24>(&generate::input(seed)).unwrap();
+        let mut forecast = Forecast::new(&valley);
+
+        let mut minute = Some(0);
+        for (from, to) in [(valley.start, valley.end), (valley.end, valley.start), (valley.start, valley.end)] {
+            let Some(start) = minute else {
+                break;
+            };
+            let expected = cross_minute_by_minute(&valley, from, to, start);
+            minute = cross(&mut forecast, from, to, start);
+            proptest::prop_assert_eq!(minute, expected);
+        }
+    }
+}
+
+#[test]
+fn test_blizzards_after_minutes_match_simulating_them() {
+    let valley = common::parse_input::<Day24>(include_str!("../example.txt")).unwrap();
+    let mut blizzards = valley.blizzards.clone();
+    for minute in 1..=30 {
+        blizzards = blizzards.iter().map(|b| b.simulate(&valley)).collect();
+        for (start, now) in valley.blizzards.iter().zip(&blizzards) {
+            assert_eq!(start.after(&valley, minute), now.position);
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use common::parse::lines_with;
+use common::stream::Streaming;
+use common::{Answer, ParseError, Solution};
+
+pub struct Day25;
+
+common::solution_functions!(Day25);
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+
+    type Input = Vec<i64>;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        lines_with(text, parse_snafu)
+    }
+
+    fn part1(numbers: &Self::Input) -> Answer {
+        process_part1(numbers).into()
+    }
+
+    fn part2(_numbers: &Self::Input) -> Answer {
+        // There is no second puzzle on the last day.
+        Answer::Empty
+    }
+}
+
+const MAX_DIGITS: usize = 27;
+
+/// Parses a SNAFU number, checking every digit before converting it.
+fn parse_snafu(line: &str) -> Result<i64, ParseError> {
+    if line.is_empty() {
+        return Err(ParseError::expected("a SNAFU number"));
+    }
+    // Any SNAFU number of this many digits fits in an i64, and the next digit may not
+    if line.len() > MAX_DIGITS {
+        return Err(ParseError::expected(format!(
+            "a SNAFU number of at most {} digits",
+            MAX_DIGITS
+        ))
+        .at_column(MAX_DIGITS + 1));
+    }
+    match line.chars().position(|c| !"012-=".contains(c)) {
+        Some(column) => Err(ParseError::expected("0, 1, 2, - or =").at_column(column + 1)),
+        None => Ok(base5_to_base10(line)),
+    }
+}
+
+impl Streaming for Day25 {
+    /// The sum of the numbers read so far, which can't overflow with numbers that fit in an `i64`.
+    type State = i128;
+
+    fn feed(sum: &mut Self::State, line: &str) -> Result<(), ParseError> {
+        *sum += i128::from(parse_snafu(line)?);
+        Ok(())
+    }
+
+    fn finish(sum: Self::State) -> Result<[Answer; 2], ParseError> {
+        Ok([base10_to_base5(sum).into(), Answer::Empty])
+    }
+}
+
+/// Convert base 5 to base 10.
+/// They symbols are 0, 1, 2, - (-1), = (-2)
+/// 0 = 0
+/// 1 = 1
+/// 2 = 2
+/// `-` = -1
+/// `=` = -2
+fn base5_to_base10(s: &str) -> i64 {
+    let mut result = 0;
+    let mut multiplier = 1;
+    for c in s.chars().rev() {
+        match c {
+            '0' => result += 0,
+            '1' => result += multiplier,
+            '2' => result += 2 * multiplier,
+            '-' => result += -multiplier,
+            '=' => result += -2 * multiplier,
+            _ => panic!("Invalid character"),
+        }
+        multiplier *= 5;
+    }
+    result
+}
+
+#[test]
+fn test_base5_to_base_10() {
+    assert_eq!(base5_to_base10("0"), 0);
+    assert_eq!(base5_to_base10("1"), 1);
+    assert_eq!(base5_to_base10("2"), 2);
+    assert_eq!(base5_to_base10("-"), -1);
+    assert_eq!(base5_to_base10("="), -2);
+    assert_eq!(base5_to_base10("1="), 3);
+    assert_eq!(base5_to_base10("1-"), 4);
+    assert_eq!(base5_to_base10("10"), 5);
+    assert_eq!(base5_to_base10("11"), 6);
+    assert_eq!(base5_to_base10("1=-0-2"), 1747);
+    assert_eq!(base5_to_base10("12111"), 906);
+    assert_eq!(base5_to_base10("2=0="), 198);
+    assert_eq!(base5_to_base10("21"), 11);
+    assert_eq!(base5_to_base10("2=01"), 201);
+    assert_eq!(base5_to_base10("111"), 31);
+    assert_eq!(base5_to_base10("20012"), 1257);
+    assert_eq!(base5_to_base10("112"), 32);
+    assert_eq!(base5_to_base10("1=-1="), 353);
+    assert_eq!(base5_to_base10("1-12"), 107);
+    assert_eq!(base5_to_base10("122"), 37);
+}
+
+/// Convert base 10 to base 5.
+/// They symbols are 0, 1, 2, - (-1), = (-2)
+/// 0 = 0
+/// 1 = 1
+/// 2 = 2
+/// `-` = -1
+/// `=` = -2
+fn base10_to_base5(decimal: i128) -> String {
+    let mut result = String::new();
+    let mut remainder = decimal;
+    while remainder != 0 {
+        let digit = remainder % 5;
+        let char = match digit {
+            digit if digit < 3 => std::char::from_digit(digit as u32, 10).unwrap(),
+            3 => {
+                remainder += 2;
+                '='
+            }
+            _ => {
+                remainder += 1;
+                '-'
+            }
+        };
+        result.push(char);
+        remainder /= 5;
+    }
+    result.chars().rev().collect()
+}
+
+#[test]
+fn test_base10_to_base5() {
+    // assert_eq!(base10_to_base5(0), "0");
+    assert_eq!(base10_to_base5(1), "1");
+    assert_eq!(base10_to_base5(2), "2");
+    // assert_eq!(base10_to_base5(-1), "-");
+    // assert_eq!(base10_to_base5(-2), "=");
+    assert_eq!(base10_to_base5(3), "1=");
+    assert_eq!(base10_to_base5(4), "1-");
+    assert_eq!(base10_to_base5(5), "10");
+    assert_eq!(base10_to_base5(6), "11");
+    assert_eq!(base10_to_base5(1747), "1=-0-2");
+    assert_eq!(base10_to_base5(906), "12111");
+    assert_eq!(base10_to_base5(198), "2=0=");
+    assert_eq!(base10_to_base5(11), "21");
+    assert_eq!(base10_to_base5(201), "2=01");
+    assert_eq!(base10_to_base5(31), "111");
+    assert_eq!(base10_to_base5(1257), "20012");
+    assert_eq!(base10_to_base5(32), "112");
+    assert_eq!(base10_to_base5(353), "1=-1=");
+    assert_eq!(base10_to_base5(107), "1-12");
+    assert_eq!(base10_to_base5(37), "122");
+}
+
+fn process_part1(numbers: &[i64]) -> String {
+    base10_to_base5(numbers.iter().copied().map(i128::from).sum())
+}
+
+#[test]
+fn test_example() {
+    let numbers = common::parse_input::<Day25>(include_str!("../example.txt")).unwrap();
+    assert_eq!(Day25::part1(&numbers), Answer::from("2=-1=0"));
+    assert_eq!(Day25::part2(&numbers), Answer::Empty);
+}
+
+#[test]
+fn test_example_streaming() {
+    let text = include_str!("../example.txt");
+    let answers = common::stream::solve::<Day25>(text.as_bytes()).unwrap();
+    assert_eq!(answers, [Answer::from("2=-1=0"), Answer::Empty]);
+}
+
+#[test]
+fn test_sum_past_i64() {
+    // The largest SNAFU number of 25 digits, 62 times, adds up to more than an i64 can hold
+    let text = vec!["2".repeat(25); 62].join("\n");
+    let sum = 62 * i128::from(base5_to_base10(&"2".repeat(25)));
+    assert!(sum > i128::from(i64::MAX));
+    let expected = Answer::from(base10_to_base5(sum));
+    assert_eq!(part1(&text).unwrap(), expected);
+    let answers = common::stream::solve::<Day25>(text.as_bytes()).unwrap();
+    assert_eq!(answers[0], expected);
+
+    assert_eq!(
+        part1(&"1".repeat(28)).unwrap_err().to_string(),
+        "day25 line 1 column 28: expected \"a SNAFU number of at most 27 digits\""
+    );
+}
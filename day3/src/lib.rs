@@ -0,0 +1,199 @@
+use common::parse::lines_with;
+use common::stream::Streaming;
+use common::{Answer, ParseError, Solution};
+
+pub struct Day3;
+
+common::solution_functions!(Day3);
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<String>;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        let rucksacks = lines_with(text, parse_rucksack)?;
+        check_groups(rucksacks.len())?;
+        Ok(rucksacks)
+    }
+
+    fn part1(rucksacks: &Self::Input) -> Answer {
+        process_part1(rucksacks).into()
+    }
+
+    fn part2(rucksacks: &Self::Input) -> Answer {
+        process_part2(rucksacks).into()
+    }
+}
+
+// PART 1
+// One Elf has the important job of loading all of the rucksacks with supplies for the jungle journey. Unfortunately, that Elf didn't quite follow the packing instructions, and so a few items now need to be rearranged.
+// Each rucksack has two large compartments. All items of a given type are meant to go into exactly one of the two compartments. The Elf that did the packing failed to follow this rule for exactly one item type per rucksack.
+// The Elves have made a list of all of the items currently in each rucksack (your puzzle input), but they need your help finding the errors. Every item type is identified by a single lowercase or uppercase letter (that is, a and A refer to different types of items).
+// The list of items for each rucksack is given as characters all on a single line. A given rucksack always has the same number of items in each of its two compartments, so the first half of the characters represent items in the first compartment, while the second half of the characters represent items in the second compartment.
+//
+// For example, suppose you have the following list of contents from six rucksacks:
+//
+// vJrwpWtwJgWrhcsFMMfFFhFp
+// jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+// PmmdzqPrVvPwwTWBwg
+// wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+// ttgJtRGJQctTZtZT
+// CrZsJsPPZsGzwwsLwLmpwMDw
+// The first rucksack contains the items vJrwpWtwJgWrhcsFMMfFFhFp, which means its first compartment contains the items vJrwpWtwJgWr, while the second compartment contains the items hcsFMMfFFhFp. The only item type that appears in both compartments is lowercase p.
+// The second rucksack's compartments contain jqHRNqRjqzjGDLGL and rsFMfFZSrLrFZsSL. The only item type that appears in both compartments is uppercase L.
+// The third rucksack's compartments contain PmmdzqPrV and vPwwTWBwg; the only common item type is uppercase P.
+// The fourth rucksack's compartments only share item type v.
+// The fifth rucksack's compartments only share item type t.
+// The sixth rucksack's compartments only share item type s.
+// To help prioritize item rearrangement, every item type can be converted to a priority:
+//
+// Lowercase item types a through z have priorities 1 through 26.
+// Uppercase item types A through Z have priorities 27 through 52.
+// In the above example, the priority of the item type that appears in both compartments of each rucksack is 16 (p), 38 (L), 42 (P), 22 (v), 20 (t), and 19 (s); the sum of these is 157.
+//
+// Find the item type that appears in both compartments of each rucksack. What is the sum of the priorities of those item types?
+
+impl Streaming for Day3 {
+    type State = Priorities;
+
+    fn feed(priorities: &mut Self::State, line: &str) -> Result<(), ParseError> {
+        let rucksack = parse_rucksack(line)?;
+        priorities.part1 += process_line(&rucksack).map_or(0, |c| u64::from(c.priority()));
+
+        priorities.group.push(rucksack);
+        if let [first, second, third] = priorities.group.as_slice() {
+            priorities.part2 +=
+                find_common_character2(first, second, third).map_or(0, |c| u64::from(c.priority()));
+            priorities.group.clear();
+        }
+        Ok(())
+    }
+
+    fn finish(priorities: Self::State) -> Result<[Answer; 2], ParseError> {
+        check_groups(priorities.group.len())?;
+        Ok([priorities.part1.into(), priorities.part2.into()])
+    }
+}
+
+/// The second part splits the rucksacks into groups of three elves, which only works out when
+/// none are left over.
+fn check_groups(rucksacks: usize) -> Result<(), ParseError> {
+    if !rucksacks.is_multiple_of(3) {
+        return Err(ParseError::expected("groups of 3 rucksacks"));
+    }
+    Ok(())
+}
+
+/// The sums of the priorities of the rucksacks read so far, with the rucksacks of the group that
+/// is not complete yet.
+#[derive(Debug, Default)]
+pub struct Priorities {
+    part1: u64,
+    part2: u64,
+    group: Vec<String>,
+}
+
+trait Priority {
+    fn priority(&self) -> u32;
+}
+
+impl Priority for char {
+    fn priority(&self) -> u32 {
+        match self {
+            'a'..='z' => *self as u32 - 'a' as u32 + 1,
+            'A'..='Z' => *self as u32 - 'A' as u32 + 27,
+            _ => 0,
+        }
+    }
+}
+
+fn parse_rucksack(line: &str) -> Result<String, ParseError> {
+    match line.chars().position(|c| !c.is_ascii_alphabetic()) {
+        Some(column) => Err(ParseError::expected("<item a-z or A-Z>").at_column(column + 1)),
+        None => Ok(line.to_string()),
+    }
+}
+
+fn find_common_character(s1: &str, s2: &str) -> Option<char> {
+    s1.chars().find(|&c| s2.contains(c))
+}
+
+fn process_line(line: &str) -> Option<char> {
+    let (s1, s2) = line.split_at(line.len() / 2);
+    find_common_character(s1, s2)
+}
+
+fn process_part1(rucksacks: &[String]) -> u64 {
+    rucksacks
+        .iter()
+        .filter_map(|line| process_line(line))
+        .map(|c| u64::from(c.priority()))
+        .sum()
+}
+
+// --- Part Two ---
+// As you finish identifying the misplaced items, the Elves come to you with another issue.
+// For safety, the Elves are divided into groups of three. Every Elf carries a badge that identifies their group. For efficiency, within each group of three Elves, the badge is the only item type carried by all three Elves. That is, if a group's badge is item type B, then all three Elves will have item type B somewhere in their rucksack, and at most two of the Elves will be carrying any other item type.
+// The problem is that someone forgot to put this year's updated authenticity sticker on the badges. All of the badges need to be pulled out of the rucksacks so the new authenticity stickers can be attached.
+// Additionally, nobody wrote down which item type corresponds to each group's badges. The only way to tell which item type is the right one is by finding the one item type that is common between all three Elves in each group.
+// Every set of three lines in your list corresponds to a single group, but each group can have a different badge item type. So, in the above example, the first group's rucksacks are the first three lines:
+//
+// vJrwpWtwJgWrhcsFMMfFFhFp
+// jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+// PmmdzqPrVvPwwTWBwg
+// And the second group's rucksacks are the next three lines:
+//
+// wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+// ttgJtRGJQctTZtZT
+// CrZsJsPPZsGzwwsLwLmpwMDw
+// In the first group, the only item type that appears in all three rucksacks is lowercase r; this must be their badges. In the second group, their badge item type must be Z.
+// Priorities for these items must still be found to organize the sticker attachment efforts: here, they are 18 (r) for the first group and 52 (Z) for the second group. The sum of these is 70.
+// Find the item type that corresponds to the badges of each three-Elf group. What is the sum of the priorities of those item types?
+
+fn find_common_character2(s1: &str, s2: &str, s3: &str) -> Option<char> {
+    s1.chars().find(|&c| s2.contains(c) && s3.contains(c))
+}
+
+fn process_part2(rucksacks: &[String]) -> u64 {
+    rucksacks
+        .chunks(3)
+        .filter_map(|chunk| find_common_character2(&chunk[0], &chunk[1], &chunk[2]))
+        .map(|c| u64::from(c.priority()))
+        .sum()
+}
+
+// fn process_part2(text: String) -> i32 {
+//     let mut sum = 0;
+//     let mut lines = text.lines();
+//     while let (Some(l1), Some(l2), Some(l3)) = (lines.next(), lines.next(), lines.next()) {
+//         if let Some(c) = find_common_character2(l1, l2, l3) {
+//             sum += c.priority() as i32;
+//         }
+//     }
+//     sum
+// }
+
+#[test]
+fn test_example() {
+    let input = common::parse_input::<Day3>(include_str!("../example.txt")).unwrap();
+    assert_eq!(Day3::part1(&input), Answer::from(157));
+    assert_eq!(Day3::part2(&input), Answer::from(70));
+}
+
+#[test]
+fn test_example_streaming() {
+    let text = include_str!("../example.txt");
+    let answers = common::stream::solve::<Day3>(text.as_bytes()).unwrap();
+    assert_eq!(answers, [Answer::from(157), Answer::from(70)]);
+}
+
+#[test]
+fn test_incomplete_group() {
+    let text = include_str!("../example.txt");
+    let text = &text[..text.trim_end().rfind('\n').unwrap()];
+    let expected = "day3: expected \"groups of 3 rucksacks\"";
+    assert_eq!(part2(text).unwrap_err().to_string(), expected);
+    let err = common::stream::solve::<Day3>(text.as_bytes()).unwrap_err();
+    assert_eq!(err.to_string(), expected);
+}
@@ -1,107 +1,11 @@
+use common::{parse_input, Solution};
+use day3::Day3;
 use std::fs;
 
 fn main() {
     // Read the text from the file input.txt
     let text = fs::read_to_string("input.txt").expect("Something went wrong reading the file");
-    println!("Part1: {}", process_part1(text.clone()));
-    println!("Part2: {}", process_part2(text));
+    let input = parse_input::<Day3>(&text).unwrap_or_else(|err| panic!("{}", err));
+    println!("Part1: {}", Day3::part1(&input));
+    println!("Part2: {}", Day3::part2(&input));
 }
-
-// PART 1
-// One Elf has the important job of loading all of the rucksacks with supplies for the jungle journey. Unfortunately, that Elf didn't quite follow the packing instructions, and so a few items now need to be rearranged.
-// Each rucksack has two large compartments. All items of a given type are meant to go into exactly one of the two compartments. The Elf that did the packing failed to follow this rule for exactly one item type per rucksack.
-// The Elves have made a list of all of the items currently in each rucksack (your puzzle input), but they need your help finding the errors. Every item type is identified by a single lowercase or uppercase letter (that is, a and A refer to different types of items).
-// The list of items for each rucksack is given as characters all on a single line. A given rucksack always has the same number of items in each of its two compartments, so the first half of the characters represent items in the first compartment, while the second half of the characters represent items in the second compartment.
-//
-// For example, suppose you have the following list of contents from six rucksacks:
-//
-// vJrwpWtwJgWrhcsFMMfFFhFp
-// jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
-// PmmdzqPrVvPwwTWBwg
-// wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
-// ttgJtRGJQctTZtZT
-// CrZsJsPPZsGzwwsLwLmpwMDw
-// The first rucksack contains the items vJrwpWtwJgWrhcsFMMfFFhFp, which means its first compartment contains the items vJrwpWtwJgWr, while the second compartment contains the items hcsFMMfFFhFp. The only item type that appears in both compartments is lowercase p.
-// The second rucksack's compartments contain jqHRNqRjqzjGDLGL and rsFMfFZSrLrFZsSL. The only item type that appears in both compartments is uppercase L.
-// The third rucksack's compartments contain PmmdzqPrV and vPwwTWBwg; the only common item type is uppercase P.
-// The fourth rucksack's compartments only share item type v.
-// The fifth rucksack's compartments only share item type t.
-// The sixth rucksack's compartments only share item type s.
-// To help prioritize item rearrangement, every item type can be converted to a priority:
-//
-// Lowercase item types a through z have priorities 1 through 26.
-// Uppercase item types A through Z have priorities 27 through 52.
-// In the above example, the priority of the item type that appears in both compartments of each rucksack is 16 (p), 38 (L), 42 (P), 22 (v), 20 (t), and 19 (s); the sum of these is 157.
-//
-// Find the item type that appears in both compartments of each rucksack. What is the sum of the priorities of those item types?
-
-trait Priority {
-    fn priority(&self) -> u32;
-}
-
-impl Priority for char {
-    fn priority(&self) -> u32 {
-        match self {
-            'a'..='z' => *self as u32 - 'a' as u32 + 1,
-            'A'..='Z' => *self as u32 - 'A' as u32 + 27,
-            _ => 0,
-        }
-    }
-}
-
-fn find_common_character(s1: &str, s2: &str) -> Option<char> {
-    s1.chars().find(|&c| s2.contains(c))
-}
-
-fn process_line(line: &str) -> Option<char> {
-    let (s1, s2) = line.split_at(line.len() / 2);
-    find_common_character(s1, s2)
-}
-
-fn process_part1(input: String) -> u32 {
-    input.lines().filter_map(process_line).map(|c| c.priority()).sum()
-}
-
-
-// --- Part Two ---
-// As you finish identifying the misplaced items, the Elves come to you with another issue.
-// For safety, the Elves are divided into groups of three. Every Elf carries a badge that identifies their group. For efficiency, within each group of three Elves, the badge is the only item type carried by all three Elves. That is, if a group's badge is item type B, then all three Elves will have item type B somewhere in their rucksack, and at most two of the Elves will be carrying any other item type.
-// The problem is that someone forgot to put this year's updated authenticity sticker on the badges. All of the badges need to be pulled out of the rucksacks so the new authenticity stickers can be attached.
-// Additionally, nobody wrote down which item type corresponds to each group's badges. The only way to tell which item type is the right one is by finding the one item type that is common between all three Elves in each group.
-// Every set of three lines in your list corresponds to a single group, but each group can have a different badge item type. So, in the above example, the first group's rucksacks are the first three lines:
-//
-// vJrwpWtwJgWrhcsFMMfFFhFp
-// jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
-// PmmdzqPrVvPwwTWBwg
-// And the second group's rucksacks are the next three lines:
-//
-// wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
-// ttgJtRGJQctTZtZT
-// CrZsJsPPZsGzwwsLwLmpwMDw
-// In the first group, the only item type that appears in all three rucksacks is lowercase r; this must be their badges. In the second group, their badge item type must be Z.
-// Priorities for these items must still be found to organize the sticker attachment efforts: here, they are 18 (r) for the first group and 52 (Z) for the second group. The sum of these is 70.
-// Find the item type that corresponds to the badges of each three-Elf group. What is the sum of the priorities of those item types?
-
-fn find_common_character2(s1: &str, s2: &str, s3: &str) -> Option<char> {
-    s1.chars().find(|&c| s2.contains(c) && s3.contains(c))
-}
-
-fn process_part2(text: String) -> i32 {
-    text.lines()
-        .collect::<Vec<_>>()
-        .chunks(3)
-        .filter_map(|chunk| find_common_character2(chunk[0], chunk[1], chunk[2]))
-        .map(|c| c.priority() as i32)
-        .sum()
-}
-
-// fn process_part2(text: String) -> i32 {
-//     let mut sum = 0;
-//     let mut lines = text.lines();
-//     while let (Some(l1), Some(l2), Some(l3)) = (lines.next(), lines.next(), lines.next()) {
-//         if let Some(c) = find_common_character2(l1, l2, l3) {
-//             sum += c.priority() as i32;
-//         }
-//     }
-//     sum
-// }
\ No newline at end of file
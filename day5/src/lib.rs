@@ -0,0 +1,303 @@
+use common::parse::number;
+use common::{Answer, ParseError, Solution};
+use regex::Regex;
+
+pub struct Day5;
+
+common::solution_functions!(Day5);
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = Supplies;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        parse_input(text)
+    }
+
+    fn part1(supplies: &Self::Input) -> Answer {
+        process_part1(supplies).into()
+    }
+
+    fn part2(supplies: &Self::Input) -> Answer {
+        process_part2(supplies).into()
+    }
+}
+
+// --- Part 1 ---
+// The expedition can depart as soon as the final supplies have been unloaded from the ships. Supplies are stored in stacks of marked crates, but because the needed supplies are buried under many other crates, the crates need to be rearranged.
+// The ship has a giant cargo crane capable of moving crates between stacks. To ensure none of the crates get crushed or fall over, the crane operator will rearrange them in a series of carefully-planned steps. After the crates are rearranged, the desired crates will be at the top of each stack.
+// The Elves don't want to interrupt the crane operator during this delicate procedure, but they forgot to ask her which crate will end up where, and they want to be ready to unload them as soon as possible so they can embark.
+// They do, however, have a drawing of the starting stacks of crates and the rearrangement procedure (your puzzle input). For example:
+//
+//     [D]
+// [N] [C]
+// [Z] [M] [P]
+// 1   2   3
+//
+// move 1 from 2 to 1
+// move 3 from 1 to 3
+// move 2 from 2 to 1
+// move 1 from 1 to 2
+// In this example, there are three stacks of crates. Stack 1 contains two crates: crate Z is on the bottom, and crate N is on top. Stack 2 contains three crates; from bottom to top, they are crates M, C, and D. Finally, stack 3 contains a single crate, P.
+//
+// Then, the rearrangement procedure is given. In each step of the procedure, a quantity of crates is moved from one stack to a different stack. In the first step of the above rearrangement procedure, one crate is moved from stack 2 to stack 1, resulting in this configuration:
+//
+// [D]
+// [N] [C]
+// [Z] [M] [P]
+// 1   2   3
+// In the second step, three crates are moved from stack 1 to stack 3. Crates are moved one at a time, so the first crate to be moved (D) ends up below the second and third crates:
+//
+// [Z]
+// [N]
+// [C] [D]
+// [M] [P]
+// 1   2   3
+// Then, both crates are moved from stack 2 to stack 1. Again, because crates are moved one at a time, crate C ends up below crate M:
+//
+// [Z]
+// [N]
+// [M]     [D]
+// [C]     [P]
+// 1   2   3
+// Finally, one crate is moved from stack 1 to stack 2:
+//
+// [Z]
+// [N]
+// [D]
+// [C] [M] [P]
+// 1   2   3
+// The Elves just need to know which crate will end up on top of each stack; in this example, the top crates are C in stack 1, M in stack 2, and Z in stack 3, so you should combine these together and give the Elves the message CMZ.
+//
+// After the rearrangement procedure completes, what crate ends up on top of each stack?
+
+fn split_starting_stacks_from_procedure(text: &str) -> Result<(Vec<&str>, Vec<&str>), ParseError> {
+    let (starting_stacks, procedure) = text.split_once("\n\n").ok_or_else(|| {
+        ParseError::expected("<empty line> before the procedure").offset_line(text.lines().count())
+    })?;
+    let mut starting_stacks: Vec<&str> = starting_stacks.lines().collect();
+    let procedure = procedure.lines().collect();
+
+    starting_stacks.pop();
+    Ok((starting_stacks, procedure))
+}
+
+#[derive(Debug, Clone)]
+struct Stack {
+    stack: Vec<char>,
+}
+
+impl Stack {
+    fn pop(&mut self) -> char {
+        self.stack.pop().unwrap()
+    }
+
+    fn push(&mut self, c: char) {
+        self.stack.push(c);
+    }
+
+    /// The crate on top, if the stack holds any.
+    fn top(&self) -> Option<char> {
+        self.stack.last().copied()
+    }
+}
+
+/// Parses the strings from bottom to top. The first element is the bottom of the stack.
+/// Returns a vector of the stacks.
+///
+/// # Example
+/// ```text
+///     [D]
+/// [N] [C]
+/// [Z] [M] [P]
+///
+/// => vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']]
+/// ```
+fn parse_stacks(stacks: &[&str]) -> Result<Vec<Stack>, ParseError> {
+    let mut parsed_stacks = Vec::new();
+
+    let count = stacks.last().map_or(0, |stack| stack.chars().count());
+    for _i in (1..count).step_by(4) {
+        parsed_stacks.push(Stack { stack: Vec::new() });
+    }
+
+    for (index, stack) in stacks.iter().enumerate().rev() {
+        for (i, char) in stack.chars().enumerate().skip(1).step_by(4) {
+            if char != ' ' {
+                parsed_stacks
+                    .get_mut(i / 4)
+                    .ok_or_else(|| {
+                        ParseError::expected("<end of line>")
+                            .at_column(i + 1)
+                            .offset_line(index)
+                    })?
+                    .stack
+                    .push(char);
+            }
+        }
+    }
+
+    Ok(parsed_stacks)
+}
+
+#[derive(Debug)]
+struct Procedure {
+    from: usize,
+    to: usize,
+    count: usize,
+}
+
+/// Reads the procedure, following how many crates every stack holds to make sure no step moves
+/// more crates than there are.
+fn parse_procedure(procedure: &[&str], stacks: &[Stack]) -> Result<Vec<Procedure>, ParseError> {
+    let re = Regex::new(r"move (\d+) from (\d+) to (\d+)").unwrap();
+    let mut heights = stacks
+        .iter()
+        .map(|stack| stack.stack.len())
+        .collect::<Vec<_>>();
+    procedure
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            parse_step(&re, line, &mut heights).map_err(|err| err.offset_line(index))
+        })
+        .collect()
+}
+
+fn parse_step(re: &Regex, line: &str, heights: &mut [usize]) -> Result<Procedure, ParseError> {
+    let stack_count = heights.len();
+    let captures = re
+        .captures(line)
+        .ok_or_else(|| ParseError::expected("move <n> from <stack> to <stack>"))?;
+
+    // The stacks are numbered from 1, and the procedure may only move crates between them
+    let expected_stack = format!("<stack 1-{}>", stack_count);
+    let stack = |token: &str| match number(line, token, &expected_stack)? {
+        stack if (1..=stack_count).contains(&stack) => Ok(stack),
+        _ => Err(ParseError::expected(expected_stack.as_str()).at(line, token)),
+    };
+
+    let step = Procedure {
+        count: number(line, &captures[1], "<n>")?,
+        from: stack(&captures[2])?,
+        to: stack(&captures[3])?,
+    };
+    let height = heights[step.from - 1];
+    if step.count > height {
+        return Err(
+            ParseError::expected(format!("<n> of at most {} crates", height))
+                .at(line, captures.get(1).unwrap().as_str()),
+        );
+    }
+    heights[step.from - 1] -= step.count;
+    heights[step.to - 1] += step.count;
+    Ok(step)
+}
+
+pub struct Supplies {
+    stacks: Vec<Stack>,
+    procedure: Vec<Procedure>,
+}
+
+fn parse_input(text: &str) -> Result<Supplies, ParseError> {
+    let (starting_stacks, procedure) = split_starting_stacks_from_procedure(text)?;
+    let stacks = parse_stacks(&starting_stacks)?;
+    // The procedure starts after the stacks, the row of stack numbers and an empty line
+    let procedure = parse_procedure(&procedure, &stacks)
+        .map_err(|err| err.offset_line(starting_stacks.len() + 2))?;
+    Ok(Supplies { stacks, procedure })
+}
+
+fn process_part1(supplies: &Supplies) -> String {
+    let mut stacks = supplies.stacks.clone();
+
+    for step in &supplies.procedure {
+        for _ in 0..step.count {
+            let c = stacks[step.from - 1].pop();
+            stacks[step.to - 1].push(c);
+        }
+    }
+
+    stacks
+        .iter()
+        .filter_map(|stack| stack.top())
+        .collect::<String>()
+}
+
+// --- Part Two ---
+// As you watch the crane operator expertly rearrange the crates, you notice the process isn't following your prediction.
+// Some mud was covering the writing on the side of the crane, and you quickly wipe it away. The crane isn't a CrateMover 9000 - it's a CrateMover 9001.
+// The CrateMover 9001 is notable for many new and exciting features: air conditioning, leather seats, an extra cup holder, and the ability to pick up and move multiple crates at once.
+// Again considering the example above, the crates begin in the same configuration:
+//
+// [D]
+// [N] [C]
+// [Z] [M] [P]
+// 1   2   3
+// Moving a single crate from stack 2 to stack 1 behaves the same as before:
+//
+// [D]
+// [N] [C]
+// [Z] [M] [P]
+// 1   2   3
+// However, the action of moving three crates from stack 1 to stack 3 means that those three moved crates stay in the same order, resulting in this new configuration:
+//
+// [D]
+// [N]
+// [C] [Z]
+// [M] [P]
+// 1   2   3
+// Next, as both crates are moved from stack 2 to stack 1, they retain their order as well:
+//
+// [D]
+// [N]
+// [C]     [Z]
+// [M]     [P]
+// 1   2   3
+// Finally, a single crate is still moved from stack 1 to stack 2, but now it's crate C that gets moved:
+//
+// [D]
+// [N]
+// [Z]
+// [M] [C] [P]
+// 1   2   3
+// In this example, the CrateMover 9001 has put the crates in a totally different order: MCD.
+//
+// Before the rearrangement process finishes, update your simulation so that the Elves know where they should stand to be ready to unload the final supplies. After the rearrangement procedure completes, what crate ends up on top of each stack?
+
+fn process_part2(supplies: &Supplies) -> String {
+    let mut stacks = supplies.stacks.clone();
+
+    for step in &supplies.procedure {
+        let mut to_move = Vec::new();
+        for _ in 0..step.count {
+            let c = stacks[step.from - 1].pop();
+            to_move.push(c);
+        }
+        for c in to_move.iter().rev() {
+            stacks[step.to - 1].push(*c);
+        }
+    }
+
+    stacks
+        .iter()
+        .filter_map(|stack| stack.top())
+        .collect::<String>()
+}
+
+#[test]
+fn test_example() {
+    let input = common::parse_input::<Day5>(include_str!("../example.txt")).unwrap();
+    assert_eq!(Day5::part1(&input), Answer::from("CMZ"));
+    assert_eq!(Day5::part2(&input), Answer::from("MCD"));
+}
+
+#[test]
+fn test_moving_more_crates_than_the_stack_holds() {
+    let text = include_str!("../example.txt").replace("move 3 from 1 to 3", "move 4 from 1 to 3");
+    assert_eq!(
+        part1(&text).unwrap_err().to_string(),
+        "day5 line 7 column 6: expected \"<n> of at most 3 crates\""
+    );
+}
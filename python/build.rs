@@ -0,0 +1,5 @@
+fn main() {
+    // Leaves the symbols of Python to be found when the module is imported, as on macOS they
+    // would otherwise have to be linked in
+    pyo3_build_config::add_extension_module_link_args();
+}
@@ -0,0 +1,184 @@
+//! The solutions as the Python module `aoc2022`, for working with them from notebooks.
+use common::{Answer, ParseError};
+use day13::Data;
+use day21::Monkey;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use std::collections::HashMap;
+
+/// Generates `solve_part`, which solves a part of a day with the functions on the puzzle text
+/// that every day's crate has.
+macro_rules! days {
+    ($($day:literal => $krate:ident),* $(,)?) => {
+        fn solve_part(day: u8, part: u8, text: &str) -> Option<Result<Answer, ParseError>> {
+            match (day, part) {
+                $(
+                    ($day, 1) => Some($krate::part1(text)),
+                    ($day, 2) => Some($krate::part2(text)),
+                )*
+                _ => None,
+            }
+        }
+    };
+}
+
+days! {
+    1 => day1,
+    2 => day2,
+    3 => day3,
+    4 => day4,
+    5 => day5,
+    6 => day6,
+    7 => day7,
+    8 => day8,
+    9 => day9,
+    10 => day10,
+    11 => day11,
+    12 => day12,
+    13 => day13,
+    14 => day14,
+    15 => day15,
+    16 => day16,
+    17 => day17,
+    18 => day18,
+    19 => day19,
+    20 => day20,
+    21 => day21,
+    22 => day22,
+    23 => day23,
+    24 => day24,
+    25 => day25,
+}
+
+fn invalid_input(err: ParseError) -> PyErr {
+    PyValueError::new_err(err.to_string())
+}
+
+/// Solves a part of a day on the puzzle input. The answer is an int, a str, a list with the rows
+/// of a screen, or None for a part without a puzzle. Raises ValueError for an invalid input.
+#[pyfunction]
+fn solve<'py>(py: Python<'py>, day: u8, part: u8, input_text: &str) -> PyResult<Bound<'py, PyAny>> {
+    // Other Python threads can go on while a slow day is solved
+    let answer = py
+        .allow_threads(|| solve_part(day, part, input_text))
+        .ok_or_else(|| {
+            PyValueError::new_err(format!("There is no puzzle for day {} part {}", day, part))
+        })?
+        .map_err(invalid_input)?;
+
+    match answer {
+        Answer::Integer(number) => Ok(number.into_pyobject(py)?.into_any()),
+        Answer::Text(text) => Ok(text.into_pyobject(py)?.into_any()),
+        Answer::Screen(rows) => Ok(PyList::new(py, rows)?.into_any()),
+        Answer::Empty => Ok(py.None().into_bound(py)),
+    }
+}
+
+/// The file system of day 7 as nested dicts, starting from the root directory. Every directory
+/// has its `name`, its total `size`, its `files` as a dict from name to size, and its
+/// `directories` as a dict from name to directory.
+#[pyfunction]
+fn day7_tree<'py>(py: Python<'py>, input_text: &str) -> PyResult<Bound<'py, PyDict>> {
+    let root = day7::parse(input_text).map_err(invalid_input)?;
+    directory_to_dict(py, &root)
+}
+
+fn directory_to_dict<'py>(
+    py: Python<'py>,
+    directory: &day7::Directory,
+) -> PyResult<Bound<'py, PyDict>> {
+    // Sorted by name, so the dicts come out the same every time
+    let mut files = directory.files().collect::<Vec<_>>();
+    files.sort();
+    let mut directories = directory.directories().collect::<Vec<_>>();
+    directories.sort_by_key(|d| d.name());
+
+    let file_dict = PyDict::new(py);
+    for (name, size) in files {
+        file_dict.set_item(name, size)?;
+    }
+    let directory_dict = PyDict::new(py);
+    for inner in directories {
+        directory_dict.set_item(inner.name(), directory_to_dict(py, inner)?)?;
+    }
+
+    let dict = PyDict::new(py);
+    dict.set_item("name", directory.name())?;
+    dict.set_item("size", directory.total_size())?;
+    dict.set_item("files", file_dict)?;
+    dict.set_item("directories", directory_dict)?;
+    Ok(dict)
+}
+
+/// The pairs of packets of day 13 as tuples of nested lists of ints.
+#[pyfunction]
+fn day13_packets<'py>(py: Python<'py>, input_text: &str) -> PyResult<Bound<'py, PyList>> {
+    let pairs = day13::parse(input_text).map_err(invalid_input)?;
+    let list = PyList::empty(py);
+    for (left, right) in &pairs {
+        list.append((packet_to_python(py, left)?, packet_to_python(py, right)?))?;
+    }
+    Ok(list)
+}
+
+fn packet_to_python<'py>(py: Python<'py>, packet: &Data) -> PyResult<Bound<'py, PyAny>> {
+    match packet {
+        Data::Number(number) => Ok(number.into_pyobject(py)?.into_any()),
+        Data::List(items) => {
+            let list = PyList::empty(py);
+            for item in items {
+                list.append(packet_to_python(py, item)?)?;
+            }
+            Ok(list.into_any())
+        }
+    }
+}
+
+/// The job of a monkey of day 21 as a tree of dicts, with the `name` of every monkey. A monkey
+/// that yells a number has its `number`, one that does math has its `operation` and the monkeys
+/// on the `left` and `right` of it.
+#[pyfunction]
+#[pyo3(signature = (input_text, monkey = "root"))]
+fn day21_expression<'py>(
+    py: Python<'py>,
+    input_text: &str,
+    monkey: &str,
+) -> PyResult<Bound<'py, PyDict>> {
+    let monkeys = day21::parse(input_text).map_err(invalid_input)?;
+    if !monkeys.contains_key(monkey) {
+        return Err(PyValueError::new_err(format!(
+            "There is no monkey {}",
+            monkey
+        )));
+    }
+    monkey_to_dict(py, &monkeys, monkey)
+}
+
+fn monkey_to_dict<'py>(
+    py: Python<'py>,
+    monkeys: &HashMap<String, Monkey>,
+    name: &str,
+) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new(py);
+    dict.set_item("name", name)?;
+    // Parsing checked that every monkey a job depends on exists
+    match &monkeys[name] {
+        Monkey::Number(number) => dict.set_item("number", number)?,
+        Monkey::Math(left, right, operation) => {
+            dict.set_item("operation", operation.to_string())?;
+            dict.set_item("left", monkey_to_dict(py, monkeys, left)?)?;
+            dict.set_item("right", monkey_to_dict(py, monkeys, right)?)?;
+        }
+    }
+    Ok(dict)
+}
+
+#[pymodule]
+fn aoc2022(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_function(wrap_pyfunction!(solve, module)?)?;
+    module.add_function(wrap_pyfunction!(day7_tree, module)?)?;
+    module.add_function(wrap_pyfunction!(day13_packets, module)?)?;
+    module.add_function(wrap_pyfunction!(day21_expression, module)?)?;
+    Ok(())
+}
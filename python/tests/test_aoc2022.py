@@ -0,0 +1,89 @@
+"""Tests of the aoc2022 module, run against the module as it is installed or built.
+
+Build and install the module with `maturin develop` first, or put the library that
+`cargo build -p aoc-python` builds on the path as `aoc2022.so`.
+"""
+import unittest
+from pathlib import Path
+
+import aoc2022
+
+ROOT = Path(__file__).resolve().parents[2]
+
+
+def example(day):
+    return (ROOT / f"day{day}" / "example.txt").read_text()
+
+
+class SolveTest(unittest.TestCase):
+    def test_numbers(self):
+        self.assertEqual(aoc2022.solve(1, 1, example(1)), 24000)
+        self.assertEqual(aoc2022.solve(1, 2, example(1)), 45000)
+        self.assertEqual(aoc2022.solve(21, 2, example(21)), 301)
+
+    def test_text(self):
+        self.assertEqual(aoc2022.solve(5, 1, example(5)), "CMZ")
+        self.assertEqual(aoc2022.solve(25, 1, example(25)), "2=-1=0")
+
+    def test_screen(self):
+        screen = aoc2022.solve(10, 2, example(10))
+        self.assertEqual(len(screen), 6)
+        self.assertEqual(screen[0], "##..##..##..##..##..##..##..##..##..##..")
+
+    def test_part_without_a_puzzle(self):
+        self.assertIsNone(aoc2022.solve(25, 2, example(25)))
+
+    def test_invalid_input(self):
+        with self.assertRaisesRegex(ValueError, "day1 line 2"):
+            aoc2022.solve(1, 1, "1000\nsnacks\n")
+
+    def test_missing_puzzle(self):
+        with self.assertRaises(ValueError):
+            aoc2022.solve(26, 1, example(1))
+        with self.assertRaises(ValueError):
+            aoc2022.solve(1, 3, example(1))
+
+
+class AccessorTest(unittest.TestCase):
+    def test_day7_tree(self):
+        root = aoc2022.day7_tree(example(7))
+        self.assertEqual(root["name"], "/")
+        self.assertEqual(root["size"], 48381165)
+        self.assertEqual(root["files"], {"b.txt": 14848514, "c.dat": 8504156})
+        self.assertEqual(sorted(root["directories"]), ["a", "d"])
+
+        e = root["directories"]["a"]["directories"]["e"]
+        self.assertEqual(e, {"name": "e", "size": 584, "files": {"i": 584}, "directories": {}})
+
+    def test_day13_packets(self):
+        pairs = aoc2022.day13_packets(example(13))
+        self.assertEqual(len(pairs), 8)
+        self.assertEqual(pairs[0], ([1, 1, 3, 1, 1], [1, 1, 5, 1, 1]))
+        self.assertEqual(pairs[1], ([[1], [2, 3, 4]], [[1], 4]))
+        self.assertEqual(pairs[5], ([], [3]))
+        self.assertEqual(pairs[6], ([[[]]], [[]]))
+
+    def test_day21_expression(self):
+        root = aoc2022.day21_expression(example(21))
+        self.assertEqual(root["name"], "root")
+        self.assertEqual(root["operation"], "+")
+        self.assertEqual(root["left"]["name"], "pppw")
+
+        self.assertEqual(aoc2022.day21_expression(example(21), "dbpl"), {"name": "dbpl", "number": 5})
+        ptdq = aoc2022.day21_expression(example(21), monkey="ptdq")
+        self.assertEqual(ptdq["operation"], "-")
+        self.assertEqual(ptdq["left"], {"name": "humn", "number": 5})
+
+        with self.assertRaises(ValueError):
+            aoc2022.day21_expression(example(21), "nobody")
+
+    def test_day21_cycle(self):
+        cycle = "root: aaaa + bbbb\naaaa: bbbb * cccc\nbbbb: aaaa - humn\ncccc: 1\nhumn: 5\n"
+        with self.assertRaisesRegex(ValueError, "day21 line 3"):
+            aoc2022.day21_expression(cycle)
+        with self.assertRaisesRegex(ValueError, "day21 line 3"):
+            aoc2022.solve(21, 1, cycle)
+
+
+if __name__ == "__main__":
+    unittest.main()